@@ -0,0 +1,289 @@
+use std::{cmp::Ordering, fmt, ops};
+
+use crate::{basis::ONBasis, component::Component, multivector::Multivector, scalar::{Real, Scalar}};
+
+/// # Dual
+///
+/// A dual number for forward mode automatic differentiation. Carries a value
+/// along with it's partial derivatives with respect to any number of
+/// parameters, so one pass gives the whole gradient.
+///
+/// Plugs into the algebra as a Real, so products, inverses, exp, log, and
+/// sqrt of Multivector<Dual> all carry derivatives through. See jacobian and
+/// gradient for the easy way in.
+///
+/// Constants have an empty gradient, which is read as all zeros, so they
+/// mix freely with variables of any length.
+#[derive(Debug, Clone)]
+pub struct Dual {
+    /// The value.
+    val: f64,
+    /// The partial derivative with respect to each parameter.
+    grad: Vec<f64>,
+}
+
+impl Dual {
+    /// # Constant
+    ///
+    /// A value with no derivatives.
+    pub fn constant(val: f64) -> Dual {
+        Dual { val, grad: vec![] }
+    }
+
+    /// # Variable
+    ///
+    /// Parameter idx of count, with the value given, IE with a derivative of
+    /// 1 with respect to itself and 0 with respect to the rest.
+    pub fn variable(val: f64, idx: usize, count: usize) -> Dual {
+        let mut grad = vec![0.0; count.max(idx + 1)];
+        grad[idx] = 1.0;
+        Dual { val, grad }
+    }
+
+    /// # Variables
+    ///
+    /// One variable for each value given, in order.
+    pub fn variables(vals: &[f64]) -> Vec<Dual> {
+        vals.iter().enumerate()
+            .map(|(idx, val)| Dual::variable(*val, idx, vals.len()))
+            .collect()
+    }
+
+    /// # Value
+    pub fn value(&self) -> f64 {
+        self.val
+    }
+
+    /// # Partial
+    ///
+    /// The derivative with respect to parameter idx.
+    pub fn partial(&self, idx: usize) -> f64 {
+        self.grad.get(idx).copied().unwrap_or(0.0)
+    }
+
+    /// # Gradient
+    ///
+    /// Every partial derivative, padded with zeros out to count.
+    pub fn gradient(&self, count: usize) -> Vec<f64> {
+        (0..count.max(self.grad.len())).map(|idx| self.partial(idx)).collect()
+    }
+
+    /// # Chain
+    ///
+    /// Applies the chain rule for a function of self, with the value and
+    /// derivative of the function at our value.
+    fn chain(&self, val: f64, deriv: f64) -> Dual {
+        Dual { val, grad: self.grad.iter().map(|x| x * deriv).collect() }
+    }
+
+    /// # Combine
+    ///
+    /// Builds the gradient of a function of two duals, from the derivatives
+    /// of the function with respect to each, IE d(f) = a d(lhs) + b d(rhs).
+    fn combine(&self, rhs: &Dual, val: f64, lhs_deriv: f64, rhs_deriv: f64) -> Dual {
+        let len = self.grad.len().max(rhs.grad.len());
+        Dual { val, grad: (0..len).map(|idx| lhs_deriv * self.partial(idx) + rhs_deriv * rhs.partial(idx)).collect() }
+    }
+}
+
+impl Scalar for Dual {
+    fn zero() -> Self {
+        Dual::constant(0.0)
+    }
+
+    fn one() -> Self {
+        Dual::constant(1.0)
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Dual::constant(val)
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.val.abs()
+    }
+}
+
+impl Real for Dual {
+    fn sqrt(&self) -> Self {
+        let root = self.val.sqrt();
+        self.chain(root, 0.5 / root)
+    }
+
+    fn exp(&self) -> Self {
+        let exp = self.val.exp();
+        self.chain(exp, exp)
+    }
+
+    fn ln(&self) -> Self {
+        self.chain(self.val.ln(), 1.0 / self.val)
+    }
+
+    fn sin(&self) -> Self {
+        self.chain(self.val.sin(), self.val.cos())
+    }
+
+    fn cos(&self) -> Self {
+        self.chain(self.val.cos(), -self.val.sin())
+    }
+
+    fn sinh(&self) -> Self {
+        self.chain(self.val.sinh(), self.val.cosh())
+    }
+
+    fn cosh(&self) -> Self {
+        self.chain(self.val.cosh(), self.val.sinh())
+    }
+
+    fn atanh(&self) -> Self {
+        self.chain(self.val.atanh(), 1.0 / (1.0 - self.val * self.val))
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        let sqr = x.val * x.val + self.val * self.val;
+        self.combine(x, self.val.atan2(x.val), x.val / sqr, -self.val / sqr)
+    }
+}
+
+impl PartialEq for Dual {
+    /// Equal only if the value and every derivative are, so a zero value
+    /// with a non-zero derivative is not dropped from a Multivector.
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.grad.len().max(other.grad.len());
+        self.val == other.val && (0..len).all(|idx| self.partial(idx) == other.partial(idx))
+    }
+}
+
+impl PartialOrd for Dual {
+    /// Compares by value. Equal values with different derivatives are
+    /// unordered.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.val.partial_cmp(&other.val)? {
+            Ordering::Equal if self != other => None,
+            order => Some(order),
+        }
+    }
+}
+
+impl fmt::Display for Dual {
+    /// Writes just the value for constants, otherwise the value and
+    /// gradient, IE (1.5; 0, 2).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.grad.iter().all(|x| *x == 0.0) {
+            return write!(f, "{}", self.val);
+        }
+        let grad = self.grad.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        write!(f, "({}; {})", self.val, grad.join(", "))
+    }
+}
+
+// Arithmetic
+
+// dual + dual
+impl ops::Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Self::Output {
+        self.combine(&rhs, self.val + rhs.val, 1.0, 1.0)
+    }
+}
+
+// dual - dual
+impl ops::Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, rhs: Dual) -> Self::Output {
+        self.combine(&rhs, self.val - rhs.val, 1.0, -1.0)
+    }
+}
+
+// dual * dual
+impl ops::Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Self::Output {
+        self.combine(&rhs, self.val * rhs.val, rhs.val, self.val)
+    }
+}
+
+// dual / dual
+impl ops::Div for Dual {
+    type Output = Dual;
+
+    fn div(self, rhs: Dual) -> Self::Output {
+        let val = self.val / rhs.val;
+        self.combine(&rhs, val, 1.0 / rhs.val, -val / rhs.val)
+    }
+}
+
+// -dual
+impl ops::Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Self::Output {
+        self.chain(-self.val, -1.0)
+    }
+}
+
+/// # Jacobian
+///
+/// The value of a Multivector valued function, along with it's partial
+/// derivative with respect to each parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jacobian {
+    /// The value at the parameters given.
+    pub value: Multivector,
+    /// The derivative of the value with respect to each parameter, in order.
+    pub partials: Vec<Multivector>,
+}
+
+impl Jacobian {
+    /// # Matrix
+    ///
+    /// The jacobian as a matrix, with one row per basis blade and one column
+    /// per parameter. Returns the bases of each row, in canonical order,
+    /// along with the matrix.
+    ///
+    /// Rows cover every basis blade in the value or any partial.
+    pub fn matrix(&self) -> (Vec<Vec<ONBasis>>, Vec<Vec<f64>>) {
+        let mut bases = self.partials.iter()
+            .chain([&self.value])
+            .flat_map(|x| x.components().iter().map(|comp| comp.bases().to_vec()))
+            .collect::<Vec<_>>();
+        bases.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        bases.dedup();
+        let matrix = bases.iter()
+            .map(|row| self.partials.iter()
+                .map(|x| x.components().iter().find(|comp| comp.bases() == row.as_slice()).map_or(0.0, |comp| comp.mag))
+                .collect())
+            .collect();
+        (bases, matrix)
+    }
+}
+
+/// # Jacobian
+///
+/// Evaluates func at params, tracking derivatives, and returns the value
+/// along with it's partial derivatives with respect to each parameter.
+///
+/// IE for a rotor R(θ) = exp(-θ/2 p1p2), the partial is -p1p2 R/2.
+pub fn jacobian(params: &[f64], func: impl Fn(&[Dual]) -> Multivector<Dual>) -> Jacobian {
+    let result = func(&Dual::variables(params));
+    let value = Multivector::new(result.components().iter()
+        .map(|x| Component::new(x.mag.value(), x.bases().to_vec()))
+        .collect());
+    let partials = (0..params.len())
+        .map(|idx| Multivector::new(result.components().iter()
+            .map(|x| Component::new(x.mag.partial(idx), x.bases().to_vec()))
+            .collect()))
+        .collect();
+    Jacobian { value, partials }
+}
+
+/// # Gradient
+///
+/// Evaluates a scalar cost at params, returning the cost and it's gradient.
+pub fn gradient(params: &[f64], func: impl Fn(&[Dual]) -> Dual) -> (f64, Vec<f64>) {
+    let result = func(&Dual::variables(params));
+    (result.value(), result.gradient(params.len()))
+}
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering::{self}, hash::Hash};
+use std::{cmp::Ordering::{self}, fmt, hash::Hash};
 
 use regex::Regex;
 
@@ -77,14 +77,6 @@ impl ONBasis {
         }
     }
 
-    pub fn to_string(&self) -> String {
-        match self {
-            ONBasis::P(id) => format!("p{}", id),
-            ONBasis::Z(id) => format!("z{}", id),
-            ONBasis::N(id) => format!("n{}", id),
-        }
-    }
-
     /// # Dot function
     /// 
     /// Used to take the inner product between two bases.
@@ -100,7 +92,7 @@ impl ONBasis {
     /// 
     /// Converts an Orthonormal Basis to a Component of magnitude 1.
     pub fn to_component(&self) -> Component {
-        Component { mag: 1.0, bases: vec![self.clone()] }
+        Component { mag: 1.0, bases: vec![*self] }
     }
 
     /// # To Vector 
@@ -143,7 +135,7 @@ impl ONBasis {
 
         // If ID can't be parsed into uint, return error.
         let Ok(id_val) = id.parse::<usize>() else {
-            return Err(String::from(format!("Id in '{val}' could not parse into integer.")));
+            return Err(format!("Id in '{val}' could not parse into integer."));
         };
 
         // turns e and ID into an ON Basis and returns.
@@ -156,6 +148,16 @@ impl ONBasis {
     }
 }
 
+impl fmt::Display for ONBasis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ONBasis::P(id) => write!(f, "p{}", id),
+            ONBasis::Z(id) => write!(f, "z{}", id),
+            ONBasis::N(id) => write!(f, "n{}", id),
+        }
+    }
+}
+
 impl PartialOrd for ONBasis {
     /// Partial Compare
     /// 
@@ -164,16 +166,7 @@ impl PartialOrd for ONBasis {
     /// Organizes them by Positive, Zero, and Negative magnitude, then by Basis Id
     /// between them.
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let val = self.sqr().partial_cmp(&other.sqr());
-        if let Some(Ordering::Equal) = val {
-            // if they are the same kind, compare ids
-            let l = self.unwrap();
-            let r = other.unwrap();
-            return l.partial_cmp(&r);
-        } else {
-            // otherwise, return the kind ordering. +, 0, -
-            return val;
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -188,10 +181,10 @@ impl Ord for ONBasis {
             // if they are the same kind, compare ids
             let l = self.unwrap();
             let r = other.unwrap();
-            return l.cmp(&r);
+            l.cmp(&r)
         } else {
             // otherwise, return the kind ordering.
-            return val;
+            val
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,536 @@
+use std::{cmp::Ordering, fmt, ops, str::FromStr};
+
+/// # Big Integer
+///
+/// An arbitrary precision integer, stored as a sign and a magnitude of 32 bit
+/// limbs, least significant first.
+///
+/// The magnitude never has leading zero limbs, and zero is never negative,
+/// so each value has exactly one representation and equality can be derived.
+///
+/// Only what exact Rationals need is here, arithmetic, comparison, and
+/// conversion to and from strings and floats.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    /// Whether the value is below zero.
+    negative: bool,
+    /// The magnitude, least significant limb first.
+    limbs: Vec<u32>,
+}
+
+/// # Decimal Chunk
+///
+/// The largest power of 10 which fits in a limb, used to convert to and from
+/// decimal strings 9 digits at a time.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+impl BigInt {
+    /// # Zero
+    ///
+    /// The integer 0.
+    pub fn zero() -> BigInt {
+        BigInt { negative: false, limbs: vec![] }
+    }
+
+    /// # One
+    ///
+    /// The integer 1.
+    pub fn one() -> BigInt {
+        BigInt::from(1i64)
+    }
+
+    /// # From Parts
+    ///
+    /// Builds an integer from a sign and magnitude, trimming the magnitude
+    /// and clearing the sign of zero.
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> BigInt {
+        trim(&mut limbs);
+        BigInt { negative: negative && !limbs.is_empty(), limbs }
+    }
+
+    /// # Is Zero
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// # Is Negative
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// # Absolute Value
+    pub fn abs(&self) -> BigInt {
+        BigInt { negative: false, limbs: self.limbs.clone() }
+    }
+
+    /// # Bits
+    ///
+    /// How many bits the magnitude needs. 0 for zero.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// # Shift Left
+    ///
+    /// Multiplies by 2^bits.
+    pub fn shl(&self, bits: usize) -> BigInt {
+        let mut limbs = vec![0; bits / 32];
+        limbs.extend(shl_limbs(&self.limbs, (bits % 32) as u32));
+        BigInt::from_parts(self.negative, limbs)
+    }
+
+    /// # Shift Right
+    ///
+    /// Divides the magnitude by 2^bits, rounding towards zero.
+    pub fn shr(&self, bits: usize) -> BigInt {
+        if bits / 32 >= self.limbs.len() {
+            return BigInt::zero();
+        }
+        let limbs = shr_limbs(&self.limbs[bits / 32..], (bits % 32) as u32);
+        BigInt::from_parts(self.negative, limbs)
+    }
+
+    /// # Division with Remainder
+    ///
+    /// Divides, rounding towards zero, returning the quotient and remainder.
+    /// The remainder takes the sign of self.
+    ///
+    /// Returns None if rhs is zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> Option<(BigInt, BigInt)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (quot, rem) = div_rem_limbs(&self.limbs, &rhs.limbs);
+        Some((BigInt::from_parts(self.negative != rhs.negative, quot),
+            BigInt::from_parts(self.negative, rem)))
+    }
+
+    /// # Greatest Common Divisor
+    ///
+    /// The largest integer which divides both, always positive, or 0 if
+    /// both are 0.
+    pub fn gcd(&self, rhs: &BigInt) -> BigInt {
+        let mut lhs = self.abs();
+        let mut rhs = rhs.abs();
+        while !rhs.is_zero() {
+            let (_, rem) = lhs.div_rem(&rhs).expect("rhs is not zero.");
+            lhs = rhs;
+            rhs = rem;
+        }
+        lhs
+    }
+
+    /// # Pow
+    ///
+    /// Raises self to the power of exp.
+    pub fn pow(&self, exp: u32) -> BigInt {
+        let mut result = BigInt::one();
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// # To f64
+    ///
+    /// The nearest f64, or an infinity if it is too large.
+    ///
+    /// Only the top 64 bits are converted, which rounds to nearest. The bits
+    /// below them can only break a tie, so if any are set, they are folded
+    /// into the lowest bit, which is well below an f64's 53 bits.
+    pub fn to_f64(&self) -> f64 {
+        let exp = self.bits().saturating_sub(64);
+        let top = self.shr(exp);
+        let mut val = 0u64;
+        for limb in top.limbs.iter().rev() {
+            val = (val << 32) | *limb as u64;
+        }
+        if exp > 0 && top.shl(exp) != *self {
+            val |= 1;
+        }
+        let val = val as f64 * 2f64.powi(exp.min(i32::MAX as usize) as i32);
+        if self.negative { -val } else { val }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(val: i64) -> Self {
+        let mag = val.unsigned_abs();
+        BigInt::from_parts(val < 0, vec![mag as u32, (mag >> 32) as u32])
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(val: u64) -> Self {
+        BigInt::from_parts(false, vec![val as u32, (val >> 32) as u32])
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_limbs(&self.limbs, &other.limbs),
+            (true, true) => cmp_limbs(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // break into base 10^9 chunks, least significant first.
+        let mut chunks = vec![];
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (quot, rem) = div_rem_limbs(&rest, &[DECIMAL_CHUNK]);
+            chunks.push(rem.first().copied().unwrap_or(0));
+            rest = quot;
+        }
+        let mut result = String::new();
+        if self.negative {
+            result.push('-');
+        }
+        result.push_str(&chunks.pop().unwrap_or(0).to_string());
+        for chunk in chunks.iter().rev() {
+            result.push_str(&format!("{:09}", chunk));
+        }
+        write!(f, "{}", result)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    /// Reads an optionally signed string of decimal digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+            return Err(format!("'{}' is not an integer.", s));
+        }
+        let mut limbs = vec![];
+        for chunk in digits.as_bytes().chunks(9) {
+            let val: u32 = std::str::from_utf8(chunk).unwrap().parse().unwrap();
+            limbs = mul_add_small(&limbs, 10u32.pow(chunk.len() as u32), val);
+        }
+        Ok(BigInt::from_parts(negative, limbs))
+    }
+}
+
+// Arithmetic
+
+// &int + &int
+impl ops::Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_limbs(&self.limbs, &rhs.limbs));
+        }
+        // opposite signs, so the larger magnitude wins.
+        match cmp_limbs(&self.limbs, &rhs.limbs) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_limbs(&rhs.limbs, &self.limbs)),
+            _ => BigInt::from_parts(self.negative, sub_limbs(&self.limbs, &rhs.limbs)),
+        }
+    }
+}
+
+// int + int
+impl ops::Add<BigInt> for BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: BigInt) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+// &int - &int
+impl ops::Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+// int - int
+impl ops::Sub<BigInt> for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: BigInt) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+// &int * &int
+impl ops::Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        BigInt::from_parts(self.negative != rhs.negative, mul_limbs(&self.limbs, &rhs.limbs))
+    }
+}
+
+// int * int
+impl ops::Mul<BigInt> for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: BigInt) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// &int / &int
+impl ops::Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Rounds towards zero. Panics if rhs is zero.
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).expect("Cannot divide by zero.").0
+    }
+}
+
+// int / int
+impl ops::Div<BigInt> for BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: BigInt) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+// &int % &int
+impl ops::Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Takes the sign of self. Panics if rhs is zero.
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).expect("Cannot divide by zero.").1
+    }
+}
+
+// int % int
+impl ops::Rem<BigInt> for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: BigInt) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+// -&int
+impl ops::Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.limbs.clone())
+    }
+}
+
+// -int
+impl ops::Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.limbs)
+    }
+}
+
+// Magnitude helpers, all working on trimmed, least significant first limbs.
+
+/// # Trim
+///
+/// Drops leading zero limbs.
+fn trim(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+/// # Compare Limbs
+fn cmp_limbs(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len().cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+/// # Add Limbs
+fn add_limbs(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len().max(rhs.len()) + 1);
+    let mut carry = 0u64;
+    for idx in 0..lhs.len().max(rhs.len()) {
+        let sum = *lhs.get(idx).unwrap_or(&0) as u64 + *rhs.get(idx).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    trim(&mut result);
+    result
+}
+
+/// # Subtract Limbs
+///
+/// lhs - rhs, where lhs is at least as large as rhs.
+fn sub_limbs(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0i64;
+    for (idx, val) in lhs.iter().enumerate() {
+        let diff = *val as i64 - *rhs.get(idx).unwrap_or(&0) as i64 - borrow;
+        result.push(diff as u32);
+        borrow = if diff < 0 { 1 } else { 0 };
+    }
+    trim(&mut result);
+    result
+}
+
+/// # Multiply Limbs
+fn mul_limbs(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0u32; lhs.len() + rhs.len()];
+    for (i, l) in lhs.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, r) in rhs.iter().enumerate() {
+            let val = *l as u64 * *r as u64 + result[i + j] as u64 + carry;
+            result[i + j] = val as u32;
+            carry = val >> 32;
+        }
+        result[i + rhs.len()] = carry as u32;
+    }
+    trim(&mut result);
+    result
+}
+
+/// # Multiply Add Small
+///
+/// limbs * mult + add, for single limb mult and add.
+fn mul_add_small(limbs: &[u32], mult: u32, add: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = add as u64;
+    for limb in limbs {
+        let val = *limb as u64 * mult as u64 + carry;
+        result.push(val as u32);
+        carry = val >> 32;
+    }
+    result.push(carry as u32);
+    trim(&mut result);
+    result
+}
+
+/// # Shift Limbs Left
+///
+/// Shifts left by less than a limb. Always one limb longer than the input.
+fn shl_limbs(limbs: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = vec![0u32; limbs.len() + 1];
+    for (idx, limb) in limbs.iter().enumerate() {
+        let val = (*limb as u64) << shift;
+        result[idx] |= val as u32;
+        result[idx + 1] = (val >> 32) as u32;
+    }
+    result
+}
+
+/// # Shift Limbs Right
+///
+/// Shifts right by less than a limb.
+fn shr_limbs(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    (0..limbs.len())
+        .map(|idx| (limbs[idx] >> shift) | limbs.get(idx + 1).map_or(0, |x| x << (32 - shift)))
+        .collect()
+}
+
+/// # Divide Limbs with Remainder
+///
+/// Long division of magnitudes, Knuth's Algorithm D. rhs must not be zero.
+fn div_rem_limbs(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_limbs(lhs, rhs) == Ordering::Less {
+        return (vec![], lhs.to_vec());
+    }
+    // single limb divisors are simple enough to do directly.
+    if rhs.len() == 1 {
+        let div = rhs[0] as u64;
+        let mut quot = vec![0u32; lhs.len()];
+        let mut rem = 0u64;
+        for idx in (0..lhs.len()).rev() {
+            let cur = (rem << 32) | lhs[idx] as u64;
+            quot[idx] = (cur / div) as u32;
+            rem = cur % div;
+        }
+        trim(&mut quot);
+        let mut rem = vec![rem as u32];
+        trim(&mut rem);
+        return (quot, rem);
+    }
+    // normalize so the top limb of the divisor has it's high bit set, which
+    // keeps each quotient guess within 2 of the truth.
+    let shift = rhs.last().unwrap().leading_zeros();
+    let div = &shl_limbs(rhs, shift)[..rhs.len()];
+    let mut rem = shl_limbs(lhs, shift);
+    let n = div.len();
+    let m = lhs.len() - n;
+    let mut quot = vec![0u32; m + 1];
+    for j in (0..=m).rev() {
+        let top = ((rem[j + n] as u64) << 32) | rem[j + n - 1] as u64;
+        let mut qhat = top / div[n - 1] as u64;
+        let mut rhat = top % div[n - 1] as u64;
+        while qhat >> 32 != 0 || qhat * div[n - 2] as u64 > ((rhat << 32) | rem[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += div[n - 1] as u64;
+            if rhat >> 32 != 0 {
+                break;
+            }
+        }
+        // subtract qhat * div from the current window.
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let prod = qhat * div[i] as u64 + carry;
+            carry = prod >> 32;
+            let diff = rem[i + j] as i64 - borrow - (prod & 0xffff_ffff) as i64;
+            rem[i + j] = diff as u32;
+            borrow = if diff < 0 { 1 } else { 0 };
+        }
+        let diff = rem[j + n] as i64 - borrow - carry as i64;
+        rem[j + n] = diff as u32;
+        // guessed one too high, add a divisor back.
+        if diff < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = rem[i + j] as u64 + div[i] as u64 + carry;
+                rem[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            rem[j + n] = rem[j + n].wrapping_add(carry as u32);
+        }
+        quot[j] = qhat as u32;
+    }
+    trim(&mut quot);
+    let mut rem = shr_limbs(&rem[..n], shift);
+    trim(&mut rem);
+    (quot, rem)
+}
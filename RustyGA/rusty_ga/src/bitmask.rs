@@ -0,0 +1,222 @@
+use crate::{basis::ONBasis, blade::Geometry, component::Component};
+
+/// # Max Generators
+///
+/// The most bases a BitSignature can hold, one per bit of the mask.
+pub const MAX_GENERATORS: usize = u64::BITS as usize;
+
+/// # Bit Signature
+///
+/// The signature of a Geometry in bitmask form. Bit i of each mask refers to
+/// the i-th basis in ONBasis order, so a mask read from the lowest bit up
+/// is a basis blade already in canonical order, matching Component.
+///
+/// Holds up to 64 bases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitSignature {
+    /// The bases, in ONBasis order, bit i being bases[i].
+    bases: Vec<ONBasis>,
+    /// Bases which square to -1.
+    negative: u64,
+    /// Bases which square to 0.
+    zero: u64,
+}
+
+impl BitSignature {
+    /// # New
+    ///
+    /// Creates the bit signature of a geometry.
+    ///
+    /// Returns None if the geometry has more than MAX_GENERATORS bases.
+    pub fn new(geometry: &Geometry) -> Option<BitSignature> {
+        if geometry.dimension() > MAX_GENERATORS {
+            return None;
+        }
+        let mut bases = geometry.bases().to_vec();
+        bases.sort();
+        let mut negative = 0;
+        let mut zero = 0;
+        for (idx, basis) in bases.iter().enumerate() {
+            if basis.is_neg() {
+                negative |= 1 << idx;
+            } else if basis.is_zero() {
+                zero |= 1 << idx;
+            }
+        }
+        Some(BitSignature { bases, negative, zero })
+    }
+
+    /// # Bases
+    ///
+    /// The bases of the signature, in bit order.
+    pub fn bases(&self) -> &[ONBasis] {
+        &self.bases
+    }
+
+    /// # Mask of
+    ///
+    /// Gets the bit of a basis, if it is in the signature.
+    pub fn mask_of(&self, basis: &ONBasis) -> Option<u64> {
+        self.bases.binary_search(basis).ok().map(|idx| 1 << idx)
+    }
+
+    /// # To Bits
+    ///
+    /// Converts a Component to it's bit form.
+    ///
+    /// Returns None if the component uses a basis outside the signature.
+    pub fn to_bits(&self, component: &Component) -> Option<BitComponent> {
+        let mut mask = 0;
+        for basis in component.bases() {
+            mask |= self.mask_of(basis)?;
+        }
+        Some(BitComponent::new(component.mag, mask))
+    }
+
+    /// # From Bits
+    ///
+    /// Converts a BitComponent back into a Component.
+    pub fn from_bits(&self, component: &BitComponent) -> Component {
+        let bases = self.bases.iter().enumerate()
+            .filter(|(idx, _)| component.mask & (1 << idx) != 0)
+            .map(|(_, basis)| *basis)
+            .collect();
+        Component::new(component.mag, bases)
+    }
+
+    /// # Metric Sign
+    ///
+    /// The sign gained by squaring the bases shared by two masks.
+    ///
+    /// Zero if any shared basis is degenerate.
+    pub fn metric_sign(&self, lhs: u64, rhs: u64) -> f64 {
+        let shared = lhs & rhs;
+        if shared & self.zero != 0 {
+            0.0
+        } else if !(shared & self.negative).count_ones().is_multiple_of(2) {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// # Bit Component
+///
+/// A compact Component, with the bases of the basis blade stored as a
+/// bitmask. The meaning of each bit comes from a BitSignature, which is needed
+/// for any product which uses the metric.
+///
+/// Avoids allocating for the bases and needs no reordering, the sign of a
+/// product comes from the parity of swaps, counted with popcount.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitComponent {
+    /// The magnitude of the component.
+    pub mag: f64,
+    /// The bases of the component, bit i being the i-th basis of the signature.
+    pub mask: u64,
+}
+
+impl BitComponent {
+    pub const ZERO: BitComponent = BitComponent { mag: 0.0, mask: 0 };
+
+    /// # New
+    ///
+    /// Creates a new BitComponent.
+    pub fn new(mag: f64, mask: u64) -> BitComponent {
+        BitComponent { mag, mask }
+    }
+
+    /// # Grade
+    ///
+    /// The grade of the component, IE how many bases it has.
+    pub fn grade(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// # Scalar Multiplication
+    ///
+    /// Multiplies the component by a scalar.
+    pub fn scalar_mult(&self, rhs: f64) -> BitComponent {
+        BitComponent::new(self.mag * rhs, self.mask)
+    }
+
+    /// # Geometric Product
+    ///
+    /// Multiplies two components together via Geometric Product.
+    ///
+    /// Identical to Component::geo_product, for components in the signature.
+    pub fn geo_product(&self, rhs: &BitComponent, signature: &BitSignature) -> BitComponent {
+        let mag = self.mag * rhs.mag
+            * reorder_sign(self.mask, rhs.mask)
+            * signature.metric_sign(self.mask, rhs.mask);
+        if mag == 0.0 {
+            return BitComponent::ZERO;
+        }
+        BitComponent::new(mag, self.mask ^ rhs.mask)
+    }
+
+    /// # Outer Product
+    ///
+    /// Takes the outer product of two components. Components which share a
+    /// basis produce a zero. Needs no metric.
+    pub fn outer_product(&self, rhs: &BitComponent) -> BitComponent {
+        let mag = self.mag * rhs.mag;
+        if self.mask & rhs.mask != 0 || mag == 0.0 {
+            return BitComponent::ZERO;
+        }
+        BitComponent::new(mag * reorder_sign(self.mask, rhs.mask), self.mask | rhs.mask)
+    }
+
+    /// # Left Contraction
+    ///
+    /// Left Contraction, which is zero unless all of lhs is within rhs.
+    pub fn left_cont(&self, rhs: &BitComponent, signature: &BitSignature) -> BitComponent {
+        if self.mask & !rhs.mask != 0 {
+            return BitComponent::ZERO;
+        }
+        self.geo_product(rhs, signature)
+    }
+
+    /// # Right Contraction
+    ///
+    /// Same as left, but reversed.
+    pub fn right_cont(&self, rhs: &BitComponent, signature: &BitSignature) -> BitComponent {
+        if rhs.mask & !self.mask != 0 {
+            return BitComponent::ZERO;
+        }
+        self.geo_product(rhs, signature)
+    }
+
+    /// # Reversion
+    ///
+    /// Reverses the component, signs following ++--.
+    pub fn reversion(&self) -> BitComponent {
+        let sign = if (self.grade() / 2).is_multiple_of(2) { 1.0 } else { -1.0 };
+        self.scalar_mult(sign)
+    }
+
+    /// # Involution
+    ///
+    /// Involutes the component, signs following +-+-.
+    pub fn involution(&self) -> BitComponent {
+        let sign = if self.grade().is_multiple_of(2) { 1.0 } else { -1.0 };
+        self.scalar_mult(sign)
+    }
+}
+
+/// # Reorder Sign
+///
+/// The sign gained from putting the bases of lhs followed by rhs into order.
+///
+/// Each basis in rhs must swap past every basis in lhs which is higher than
+/// it, so we count those for every basis in rhs and use the parity.
+pub fn reorder_sign(lhs: u64, rhs: u64) -> f64 {
+    let mut lhs = lhs >> 1;
+    let mut swaps = 0;
+    while lhs != 0 {
+        swaps += (lhs & rhs).count_ones();
+        lhs >>= 1;
+    }
+    if swaps.is_multiple_of(2) { 1.0 } else { -1.0 }
+}
@@ -1,4 +1,6 @@
-use crate::{basis::ONBasis, component::Component, multivector::Multivector, vector::Vector};
+use std::collections::HashMap;
+
+use crate::{basis::ONBasis, bitmask::BitSignature, component::Component, multivector::{null_space, Multivector}, scalar::Scalar, vector::Vector};
 
 pub const ZERO: Blade = Blade{ components: vec![], vectors: vec![] };
 
@@ -15,19 +17,28 @@ pub const ZERO: Blade = Blade{ components: vec![], vectors: vec![] };
 /// An Example of a Non-blade (R^4)
 ///     2 e_12 + 3 e_34
 #[derive(Debug, Clone, PartialEq)]
-pub struct Blade {
+pub struct Blade<T: Scalar = f64> {
     // # Component
     // 
     // The Components of the blade. Straight interpretation.
-    pub components: Vec<Component>,
+    pub components: Vec<Component<T>>,
     /// # Vectors
     /// 
     /// The vectors which make up the blade. Makes decomposition easy.
-    pub vectors: Vec<Vector>,
+    pub vectors: Vec<Vector<T>>,
 }
 
 impl Blade {
     pub const ZERO: Self = Self { components: vec![], vectors: vec![] };
+}
+
+impl<T: Scalar> Blade<T> {
+    /// # Zero
+    /// 
+    /// The blade with no components or vectors, for any Scalar.
+    pub fn zero() -> Blade<T> {
+        Blade { components: vec![], vectors: vec![] }
+    }
 
     /// # Component length
     /// 
@@ -53,38 +64,97 @@ impl Blade {
     /// # New
     /// 
     /// Creates a new blade from a list of vectors.
-    pub fn new(vectors: &Vec<Vector>) -> Self {
-        let mut result = ZERO;
-        result.vectors = vectors.clone();
-        let mut mv = Multivector::ZERO;
+    /// 
+    /// The components are the outer product of the vectors. If the vectors 
+    /// are not linearly independent, that product is zero, and so the blade 
+    /// is ZERO, with no vectors, keeping the two consistent.
+    /// 
+    /// A blade of no vectors is the scalar 1.
+    pub fn new(vectors: &[Vector<T>]) -> Self {
+        let mut components = vec![Component::new(T::one(), vec![])];
         for vec in vectors.iter() {
-            for comp in vec.components.iter() {
-
+            let mut next = Multivector::zero();
+            for lhs in components.iter() {
+                for rhs in vec.components.iter() {
+                    next.components.push(lhs.outer_product(rhs));
+                }
             }
+            components = next.consolidate_components().components;
+            components.retain(|x| !x.mag.is_zero());
+        }
+        if components.is_empty() {
+            return Blade::zero();
+        }
+        Self { components, vectors: vectors.to_vec() }
+    }
+
+    /// # Outer Product
+    /// 
+    /// The outer product of two blades, which is another blade, made from
+    /// the vectors of both.
+    pub fn outer_product(&self, rhs: &Blade<T>) -> Blade<T> {
+        let mut vectors = self.vectors.clone();
+        vectors.extend(rhs.vectors.iter().cloned());
+        let mut result = Blade::new(&vectors);
+        // carry over any scalar factor not held by the vectors.
+        if self.grade() == 0 || rhs.grade() == 0 {
+            let scale = [self, rhs].iter()
+                .filter(|x| x.grade() == 0)
+                .map(|x| x.components.first().map_or(T::zero(), |c| c.mag.clone()))
+                .fold(T::one(), |acc, x| acc * x);
+            result = result.scalar_mult(scale);
+        }
+        result
+    }
+
+    /// # Scalar Multiplication
+    /// 
+    /// Multiplies the blade by a scalar, scaling the first vector to match.
+    pub fn scalar_mult(&self, scalar: T) -> Blade<T> {
+        if scalar.is_zero() || self.components.is_empty() {
+            return Blade::zero();
+        }
+        let mut result = self.clone();
+        for comp in result.components.iter_mut() {
+            comp.mag = comp.mag.clone() * scalar.clone();
+        }
+        if let Some(first) = result.vectors.first_mut() {
+            *first = first.scalar_mult(scalar);
         }
         result
     }
 
-    
+    /// # From Multivector
+    ///
+    /// Converts a Multivector to a Blade, breaking it down into it's vectors.
+    ///
+    /// Returns None if the multivector is not a blade. Zero becomes ZERO.
+    pub fn from_mv(mv: &Multivector<T>) -> Option<Self> {
+        if mv.components.is_empty() {
+            return Some(Blade::zero());
+        }
+        let vectors = mv.blade_breakdown()?;
+        Some(Self { components: mv.components.clone(), vectors })
+    }
 
     /// # From Component
     /// 
     /// Converts a Component to A Blade. All components are blades of grade N.
-    pub fn from_component(component: &Component) -> Self {
+    pub fn from_component(component: &Component<T>) -> Self {
         Self { components: vec![component.clone()], vectors: component.vector_decomposition()}
     }
 
     /// # From Vector 
     /// 
     /// Converts a Vector to a Blade, valid as all vectors are blades.
-    pub fn from_vector(vector: &Vector) -> Self {
+    pub fn from_vector(vector: &Vector<T>) -> Self {
         Self { components: vector.components.clone(), vectors: vec![vector.clone()] }
     }
 
     /// # To Multivector
     /// 
     /// Converts the Blade to a Multivector.
-    pub fn to_mv(&self) -> Multivector {
+    pub fn to_mv(&self) -> Multivector<T> {
         Multivector { 
             components: self.components.clone(), 
             blades: vec![self.clone()]
@@ -92,11 +162,476 @@ impl Blade {
     }
 }
 
-/// Geometry, the context of our 
+impl Blade {
+    /// # Meet
+    ///
+    /// The intersection of the subspaces of two blades, the largest blade
+    /// contained in both. Found as the null space of [A, -B], the vectors of
+    /// both blades side by side, as each solution is a vector both can make.
+    ///
+    /// - If the subspace of self lies within rhs, including when they
+    ///   coincide, the meet is self.
+    /// - If the subspace of rhs lies within self, the meet is rhs.
+    /// - If they only share the origin, the meet is the scalar 1.
+    /// - Otherwise, it is scaled to a coefficient norm of 1, IE the sum of
+    ///   squared magnitudes is 1, as the meet has no natural scale.
+    ///
+    /// The meet of ZERO with anything is ZERO.
+    pub fn meet(&self, rhs: &Blade) -> Blade {
+        if self.components.is_empty() || rhs.components.is_empty() {
+            return ZERO;
+        }
+        let matrix = vector_matrix(&[(&self.vectors, 1.0), (&rhs.vectors, -1.0)]);
+        let solutions = null_space(matrix, self.grade() + rhs.grade());
+        if solutions.len() == self.grade() {
+            return self.clone();
+        }
+        if solutions.len() == rhs.grade() {
+            return rhs.clone();
+        }
+        // each solution is a combination of the vectors of self.
+        let vectors: Vec<Vector> = solutions.iter()
+            .map(|sol| {
+                let mut result = Vector::ZERO;
+                for (weight, vec) in sol.iter().zip(self.vectors.iter()) {
+                    result = result.vec_add(&vec.scalar_mult(*weight));
+                }
+                result
+            })
+            .collect();
+        let result = Blade::new(&vectors);
+        let norm = result.components.iter().map(|x| x.mag * x.mag).sum::<f64>().sqrt();
+        result.scalar_mult(1.0 / norm)
+    }
+
+    /// # Join
+    ///
+    /// The union of the subspaces of two blades, the smallest blade which
+    /// contains both. Found by adding each vector of rhs onto self, skipping
+    /// those already within the span so far.
+    ///
+    /// - If the subspace of rhs lies within self, including when they
+    ///   coincide, the join is self.
+    /// - If they only share the origin, the join is self ^ rhs.
+    /// - Otherwise, it is self ^ the vectors of rhs which were not skipped.
+    ///
+    /// The join of ZERO with anything is ZERO.
+    pub fn join(&self, rhs: &Blade) -> Blade {
+        if self.components.is_empty() || rhs.components.is_empty() {
+            return ZERO;
+        }
+        let mut vectors = self.vectors.clone();
+        for vec in rhs.vectors.iter() {
+            vectors.push(vec.clone());
+            // a dependent vector leaves room for a solution.
+            if !null_space(vector_matrix(&[(&vectors, 1.0)]), vectors.len()).is_empty() {
+                vectors.pop();
+            }
+        }
+        if vectors.len() == self.grade() {
+            return self.clone();
+        }
+        if vectors.len() == self.grade() + rhs.grade() {
+            return self.outer_product(rhs);
+        }
+        let added = Blade::new(&vectors[self.grade()..]);
+        self.outer_product(&added)
+    }
+}
+
+/// # Vector Matrix
+/// 
+/// The matrix with one column per vector, and one row per basis used by any 
+/// of them. Each group of vectors is scaled by it's sign.
+fn vector_matrix(groups: &[(&[Vector], f64)]) -> Vec<Vec<f64>> {
+    let mut bases: Vec<ONBasis> = vec![];
+    for (vectors, _) in groups {
+        for comp in vectors.iter().flat_map(|x| x.components.iter()) {
+            if !bases.contains(&comp.bases()[0]) {
+                bases.push(comp.bases()[0]);
+            }
+        }
+    }
+    let cols = groups.iter().map(|(vectors, _)| vectors.len()).sum();
+    let mut matrix = vec![vec![0.0; cols]; bases.len()];
+    let vectors = groups.iter()
+        .flat_map(|(vectors, sign)| vectors.iter().map(move |x| (x, *sign)));
+    for (col, (vector, sign)) in vectors.enumerate() {
+        for comp in vector.components.iter() {
+            let row = bases.iter().position(|x| *x == comp.bases()[0]).unwrap();
+            matrix[row][col] += sign * comp.mag;
+        }
+    }
+    matrix
+}
+
+/// # Geometry
+/// 
+/// The context of our algebra. Defines which orthonormal bases exist, what 
+/// they are named, and what order they come in.
+/// 
+/// The signature (p, q, r) is the number of bases which square to positive, 
+/// negative, and zero respectively.
+/// 
+/// Multivectors built through a geometry are checked to only use that 
+/// geometry's bases, so mixing two geometries results in an error.
+/// 
+/// A geometry may carry a zero threshold, below which components are 
+/// dropped from the results of it's product wrappers, like geo_product and 
+/// sandwich. See with_zero_threshold.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Geometry {
-    /// The Orthonormal Bases of this geometry.
-    _onbases: Vec<ONBasis>,
-    /// Other available spaces/bases in this Geometry. These are not 
-    /// guaranteed to be orthonormal.
-    _spaces: Vec<Vec<ONBasis>>,
-}
\ No newline at end of file
+    /// The Orthonormal Bases of this geometry, in order.
+    bases: Vec<ONBasis>,
+    /// The names of the bases, matching the order of bases.
+    names: Vec<String>,
+    /// Components with a magnitude at or below this are dropped by the 
+    /// product wrappers, if set.
+    zero_threshold: Option<f64>,
+}
+
+impl Geometry {
+    /// # New
+    /// 
+    /// Creates a geometry with signature (p, q, r).
+    /// 
+    /// Positive bases are P(1) to P(p), negative bases are N(1) to N(q), and 
+    /// degenerate bases are Z(0) to Z(r-1). Each is named after the basis, IE
+    /// p1, n1, z0.
+    pub fn new(p: usize, q: usize, r: usize) -> Geometry {
+        let bases: Vec<ONBasis> = (1..=p).map(ONBasis::P)
+            .chain((1..=q).map(ONBasis::N))
+            .chain((0..r).map(ONBasis::Z))
+            .collect();
+        let names = bases.iter().map(|x| x.to_string()).collect();
+        Geometry { bases, names, zero_threshold: None }
+    }
+
+    /// # From Named
+    /// 
+    /// Creates a geometry from a list of named bases, in the order given.
+    /// 
+    /// Returns an error if any name or basis is repeated, or a name is empty.
+    pub fn from_named(named: Vec<(String, ONBasis)>) -> Result<Geometry, String> {
+        let mut result = Geometry { bases: vec![], names: vec![], zero_threshold: None };
+        for (name, basis) in named {
+            if name.is_empty() {
+                return Err(format!("Basis '{}' was given an empty name.", basis));
+            } else if result.names.contains(&name) {
+                return Err(format!("The name '{}' is used more than once.", name));
+            } else if result.bases.contains(&basis) {
+                return Err(format!("Basis '{}' is used more than once.", basis));
+            }
+            result.names.push(name);
+            result.bases.push(basis);
+        }
+        Ok(result)
+    }
+
+    /// # With Zero Threshold
+    /// 
+    /// The same geometry, but dropping any component with a magnitude of at
+    /// most eps from the results of it's product wrappers (geo_product, 
+    /// outer_product, left_cont, right_cont, regressive, and sandwich), so 
+    /// rounding noise like 1e-17 does not build up.
+    /// 
+    /// This is opt-in. The operators on Multivector, and so the interpreter, 
+    /// never use it; call clean on their results to do the same.
+    pub fn with_zero_threshold(self, eps: f64) -> Geometry {
+        Geometry { zero_threshold: Some(eps), ..self }
+    }
+
+    /// # Zero Threshold
+    /// 
+    /// The zero threshold of the geometry, if it has one.
+    pub fn zero_threshold(&self) -> Option<f64> {
+        self.zero_threshold
+    }
+
+    /// # Signature
+    /// 
+    /// The (p, q, r) signature of the geometry.
+    pub fn signature(&self) -> (usize, usize, usize) {
+        (
+            self.bases.iter().filter(|x| x.is_pos()).count(),
+            self.bases.iter().filter(|x| x.is_neg()).count(),
+            self.bases.iter().filter(|x| x.is_zero()).count(),
+        )
+    }
+
+    /// # Dimension
+    /// 
+    /// How many bases the geometry has.
+    pub fn dimension(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// # Bases
+    /// 
+    /// The bases of the geometry, in order.
+    pub fn bases(&self) -> &[ONBasis] {
+        &self.bases
+    }
+
+    /// # Bit Signature
+    /// 
+    /// The signature of the geometry in bitmask form, for use with 
+    /// BitComponents.
+    /// 
+    /// Returns None if the geometry has more than 64 bases.
+    pub fn bit_signature(&self) -> Option<BitSignature> {
+        BitSignature::new(self)
+    }
+
+    /// # Names
+    /// 
+    /// The names of the bases, in order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// # Names Map
+    /// 
+    /// The names of the bases mapped to their basis, for use in 
+    /// from_string_named and to_string_named.
+    pub fn names_map(&self) -> HashMap<String, ONBasis> {
+        self.names.iter().cloned().zip(self.bases.iter().copied()).collect()
+    }
+
+    /// # Name Of
+    /// 
+    /// Gets the name of a basis, if it is in the geometry.
+    pub fn name_of(&self, basis: &ONBasis) -> Option<&str> {
+        self.bases.iter().position(|x| x == basis)
+            .map(|idx| self.names[idx].as_str())
+    }
+
+    /// # Basis
+    /// 
+    /// Gets the basis with the given name, if it exists.
+    pub fn basis(&self, name: &str) -> Option<ONBasis> {
+        self.names.iter().position(|x| x == name)
+            .map(|idx| self.bases[idx])
+    }
+
+    /// # Contains
+    /// 
+    /// Checks whether the basis is part of this geometry.
+    pub fn contains(&self, basis: &ONBasis) -> bool {
+        self.bases.contains(basis)
+    }
+
+    /// # Zero
+    /// 
+    /// The zero element of the geometry.
+    pub fn zero(&self) -> Multivector {
+        Multivector::ZERO
+    }
+
+    /// # One
+    /// 
+    /// The unit scalar of the geometry.
+    pub fn one(&self) -> Multivector {
+        Multivector::new(vec![Component::from_float(&1.0)])
+    }
+
+    /// # Pseudoscalar
+    /// 
+    /// The outer product of all bases in the geometry, in the geometry's order.
+    pub fn pseudoscalar(&self) -> Component {
+        Component::new(1.0, self.bases.clone())
+    }
+
+    /// # Complement
+    /// 
+    /// The right complement of mv, taking each basis blade A to the blade B
+    /// with A ^ B = I, the pseudoscalar. Unlike the dual, it ignores the 
+    /// metric, so works with degenerate bases.
+    pub fn complement<T: Scalar>(&self, mv: &Multivector<T>) -> Multivector<T> {
+        self.complement_sided(mv, true)
+    }
+
+    /// # Inverse Complement
+    /// 
+    /// The left complement, which undoes complement. Takes each basis blade
+    /// A to the blade B with B ^ A = I.
+    pub fn inverse_complement<T: Scalar>(&self, mv: &Multivector<T>) -> Multivector<T> {
+        self.complement_sided(mv, false)
+    }
+
+    /// # Complement Sided
+    /// 
+    /// The right or left complement of mv.
+    fn complement_sided<T: Scalar>(&self, mv: &Multivector<T>, right: bool) -> Multivector<T> {
+        let pseudoscalar = self.pseudoscalar();
+        let mut result = vec![];
+        for comp in mv.components() {
+            let others: Vec<ONBasis> = self.bases.iter()
+                .filter(|x| !comp.bases().contains(x))
+                .copied()
+                .collect();
+            let unit = Component::new(1.0, comp.bases().to_vec());
+            let other = Component::new(1.0, others);
+            let product = if right { &unit ^ &other } else { &other ^ &unit };
+            let sign = T::from_f64(other.mag * product.mag / pseudoscalar.mag);
+            result.push(Component::new(comp.mag.clone() * sign, other.bases));
+        }
+        Multivector::new(result)
+    }
+
+    /// # Basis Blades
+    /// 
+    /// All 2^n unit basis blades of the geometry, from the scalar up to the 
+    /// pseudoscalar, ordered by grade.
+    /// 
+    /// Within a grade, blades are ordered by the geometry's basis order, and
+    /// each is the product of its bases in that order, so the last blade is
+    /// the pseudoscalar. For a geometry not in canonical order, like y, x, 
+    /// some blades are negated, IE yx is -1 P(1)P(2).
+    pub fn basis_blades(&self) -> Vec<Component> {
+        let mut result = vec![];
+        for grade in 0..=self.dimension() {
+            for mask in 0..(1_usize << self.dimension()) {
+                if mask.count_ones() as usize != grade {
+                    continue;
+                }
+                let bases: Vec<ONBasis> = self.bases.iter().enumerate()
+                    .filter(|(idx, _)| mask & (1 << idx) != 0)
+                    .map(|(_, b)| *b)
+                    .collect();
+                result.push(Component::new(1.0, bases));
+            }
+        }
+        result
+    }
+
+    /// # Check
+    /// 
+    /// Checks that a multivector only uses bases from this geometry.
+    /// 
+    /// Returns an error naming the first basis which is not.
+    pub fn check<T: Scalar>(&self, mv: &Multivector<T>) -> Result<(), String> {
+        for comp in mv.components() {
+            if let Some(basis) = comp.bases().iter().find(|x| !self.contains(x)) {
+                return Err(format!("Basis '{}' is not part of this geometry.", basis));
+            }
+        }
+        Ok(())
+    }
+
+    /// # Multivector
+    /// 
+    /// Builds a multivector from components, checking that they all belong to
+    /// this geometry.
+    pub fn mv(&self, components: Vec<Component>) -> Result<Multivector, String> {
+        let result = Multivector::new(components);
+        self.check(&result)?;
+        Ok(result)
+    }
+
+    /// # From String
+    /// 
+    /// Reads a multivector using the geometry's basis names, checking that
+    /// it belongs to this geometry.
+    pub fn from_string(&self, val: &str) -> Result<Multivector, String> {
+        let result = Multivector::from_string_named(val, &self.names_map())?;
+        self.check(&result)?;
+        Ok(result)
+    }
+
+    /// # Clean
+    /// 
+    /// Drops any components at or below the zero threshold, or leaves mv as
+    /// is if there is none.
+    pub fn clean<T: Scalar>(&self, mv: &Multivector<T>) -> Multivector<T> {
+        match self.zero_threshold {
+            Some(eps) => mv.clean(eps),
+            None => mv.clone(),
+        }
+    }
+
+    /// # Geometric Product
+    /// 
+    /// lhs * rhs, cleaned with the zero threshold.
+    /// 
+    /// Returns an error if either uses a basis outside of the geometry.
+    pub fn geo_product<T: Scalar>(&self, lhs: &Multivector<T>, rhs: &Multivector<T>) -> Result<Multivector<T>, String> {
+        self.check(lhs)?;
+        self.check(rhs)?;
+        Ok(self.clean(&(lhs * rhs)))
+    }
+
+    /// # Outer Product
+    /// 
+    /// lhs ^ rhs, cleaned with the zero threshold.
+    /// 
+    /// Returns an error if either uses a basis outside of the geometry.
+    pub fn outer_product<T: Scalar>(&self, lhs: &Multivector<T>, rhs: &Multivector<T>) -> Result<Multivector<T>, String> {
+        self.check(lhs)?;
+        self.check(rhs)?;
+        Ok(self.clean(&(lhs ^ rhs)))
+    }
+
+    /// # Left Contraction
+    /// 
+    /// The left contraction of lhs onto rhs, cleaned with the zero threshold.
+    /// 
+    /// Returns an error if either uses a basis outside of the geometry.
+    pub fn left_cont<T: Scalar>(&self, lhs: &Multivector<T>, rhs: &Multivector<T>) -> Result<Multivector<T>, String> {
+        self.check(lhs)?;
+        self.check(rhs)?;
+        Ok(self.clean(&lhs.left_cont_mv(rhs)))
+    }
+
+    /// # Right Contraction
+    /// 
+    /// The right contraction of lhs by rhs, cleaned with the zero threshold.
+    /// 
+    /// Returns an error if either uses a basis outside of the geometry.
+    pub fn right_cont<T: Scalar>(&self, lhs: &Multivector<T>, rhs: &Multivector<T>) -> Result<Multivector<T>, String> {
+        self.check(lhs)?;
+        self.check(rhs)?;
+        Ok(self.clean(&lhs.right_cont_mv(rhs)))
+    }
+
+    /// # Regressive Product
+    /// 
+    /// lhs v rhs, cleaned with the zero threshold.
+    /// 
+    /// Returns an error if either uses a basis outside of the geometry.
+    pub fn regressive<T: Scalar>(&self, lhs: &Multivector<T>, rhs: &Multivector<T>) -> Result<Multivector<T>, String> {
+        self.check(lhs)?;
+        self.check(rhs)?;
+        Ok(lhs.regressive(rhs, self))
+    }
+
+    /// # Sandwich
+    /// 
+    /// The versor product V X V^-1, cleaned with the zero threshold, as used 
+    /// to rotate or reflect X by V. As with Versor::apply, X is grade 
+    /// involuted first for odd versors, so reflections come out with the 
+    /// right sign.
+    /// 
+    /// Returns an error if either uses a basis outside of the geometry, or 
+    /// the versor mixes even and odd grades or has no inverse.
+    pub fn sandwich<T: Scalar>(&self, versor: &Multivector<T>, mv: &Multivector<T>) -> Result<Multivector<T>, String> {
+        self.check(versor)?;
+        self.check(mv)?;
+        let odd = versor.components().iter().any(|x| x.grade() % 2 == 1);
+        if odd && versor.components().iter().any(|x| x.grade() % 2 == 0) {
+            return Err(format!("'{}' mixes even and odd grades, so is not a versor.", versor));
+        }
+        let Some(inverse) = versor.inverse() else {
+            return Err(format!("'{}' has no inverse, so is not a versor.", versor));
+        };
+        let mv = if odd { mv.grade_involution() } else { mv.clone() };
+        Ok(self.clean(&(versor * mv * inverse)))
+    }
+
+    /// # To String
+    /// 
+    /// Writes a multivector using the geometry's basis names.
+    pub fn to_string(&self, mv: &Multivector) -> String {
+        mv.to_string_named(&self.names_map())
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{basis::ONBasis, blade::Geometry, component::Component, multivector::Multivector, versor::Versor};
+
+/// # Conformal Geometric Algebra
+///
+/// CGA, Cl(n+1,1). Euclidean space of n dimensions, with the bases e1 to en
+/// as P(1) to P(n), plus e+ as P(n+1) and e- as N(1).
+///
+/// From those we get the null bases
+///
+/// - e0 = (e- - e+)/2, the origin.
+/// - e∞ = e- + e+, the point at infinity.
+///
+/// where e0.e∞ = -1.
+///
+/// Euclidean points become null vectors through up. Spheres and planes are
+/// vectors in their dual form, where a point X is on the sphere S when
+/// X.S = 0. Circles and point pairs are the outer product of the points
+/// they pass through. The intersection of dual forms is their outer product,
+/// and the dual moves between the two forms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cga {
+    /// How many euclidean dimensions we have.
+    dimension: usize,
+    /// The geometry, with bases named e1 to en, ep, and em.
+    geometry: Geometry,
+}
+
+impl Cga {
+    /// # New
+    ///
+    /// Creates CGA for euclidean space of n dimensions.
+    ///
+    /// Returns an error if n is 0.
+    pub fn new(dimension: usize) -> Result<Cga, String> {
+        if dimension == 0 {
+            return Err(String::from("CGA needs at least 1 dimension."));
+        }
+        let mut named: Vec<(String, ONBasis)> = (1..=dimension)
+            .map(|i| (format!("e{}", i), ONBasis::P(i)))
+            .collect();
+        named.push((String::from("ep"), ONBasis::P(dimension + 1)));
+        named.push((String::from("em"), ONBasis::N(1)));
+        Ok(Cga { dimension, geometry: Geometry::from_named(named)? })
+    }
+
+    /// # Dimension
+    ///
+    /// The number of euclidean dimensions.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// # Geometry
+    ///
+    /// The geometry Cl(n+1,1), with bases named e1 to en, ep, and em.
+    pub fn geometry(&self) -> &Geometry {
+        &self.geometry
+    }
+
+    /// # e0
+    ///
+    /// The null basis for the origin, (e- - e+)/2.
+    pub fn e0(&self) -> Multivector {
+        Multivector::new(vec![
+            Component::new(0.5, vec![ONBasis::N(1)]),
+            Component::new(-0.5, vec![ONBasis::P(self.dimension + 1)]),
+        ])
+    }
+
+    /// # e∞
+    ///
+    /// The null basis for the point at infinity, e- + e+.
+    pub fn einf(&self) -> Multivector {
+        Multivector::new(vec![
+            Component::new(1.0, vec![ONBasis::N(1)]),
+            Component::new(1.0, vec![ONBasis::P(self.dimension + 1)]),
+        ])
+    }
+
+    /// # Pseudoscalar
+    ///
+    /// The unit pseudoscalar of the geometry.
+    pub fn pseudoscalar(&self) -> Multivector {
+        self.geometry.pseudoscalar().to_mv()
+    }
+
+    /// # Check Coordinates
+    ///
+    /// Ensures we have one coordinate per dimension.
+    fn check_coords(&self, coords: &[f64]) -> Result<(), String> {
+        if coords.len() == self.dimension {
+            Ok(())
+        } else {
+            Err(format!("Expected {} coordinates, found {}.", self.dimension, coords.len()))
+        }
+    }
+
+    /// # Euclidean
+    ///
+    /// The euclidean vector of the given coordinates.
+    fn euclidean(&self, coords: &[f64]) -> Multivector {
+        Multivector::new(coords.iter().enumerate()
+            .map(|(idx, val)| Component::new(*val, vec![ONBasis::P(idx + 1)]))
+            .collect())
+    }
+
+    /// # Up
+    ///
+    /// Converts euclidean coordinates x to the conformal point
+    /// e0 + x + x^2 e∞/2.
+    pub fn up(&self, coords: &[f64]) -> Result<Multivector, String> {
+        self.check_coords(coords)?;
+        let sqr: f64 = coords.iter().map(|x| x * x).sum();
+        Ok(self.e0() + self.euclidean(coords) + self.einf() * (sqr / 2.0))
+    }
+
+    /// # Down
+    ///
+    /// Converts a conformal point back to euclidean coordinates, after
+    /// scaling it so -X.e∞ = 1.
+    ///
+    /// Returns None if the point is at infinity.
+    pub fn down(&self, point: &Multivector) -> Option<Vec<f64>> {
+        let weight = -dot(point, &self.einf());
+        if weight.abs() <= f64::EPSILON {
+            return None;
+        }
+        let mut result = vec![0.0; self.dimension];
+        for comp in point.components().iter().filter(|x| x.grade() == 1) {
+            if let ONBasis::P(idx) = comp.bases()[0] {
+                if idx <= self.dimension {
+                    result[idx - 1] = comp.mag / weight;
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// # Sphere
+    ///
+    /// The dual sphere with the given center and radius, up(c) - r^2 e∞/2.
+    pub fn sphere(&self, center: &[f64], radius: f64) -> Result<Multivector, String> {
+        Ok(self.up(center)? - self.einf() * (radius * radius / 2.0))
+    }
+
+    /// # Plane
+    ///
+    /// The dual plane with the given unit normal, at distance from the origin,
+    /// n + d e∞.
+    pub fn plane(&self, normal: &[f64], distance: f64) -> Result<Multivector, String> {
+        self.check_coords(normal)?;
+        Ok(self.euclidean(normal) + self.einf() * distance)
+    }
+
+    /// # Circle
+    ///
+    /// The circle through three points, the outer product of their
+    /// conformal points.
+    pub fn circle(&self, a: &[f64], b: &[f64], c: &[f64]) -> Result<Multivector, String> {
+        Ok(self.up(a)? ^ self.up(b)? ^ self.up(c)?)
+    }
+
+    /// # Point Pair
+    ///
+    /// The pair of two points, the outer product of their conformal points.
+    pub fn point_pair(&self, a: &[f64], b: &[f64]) -> Result<Multivector, String> {
+        Ok(self.up(a)? ^ self.up(b)?)
+    }
+
+    /// # Split Pair
+    ///
+    /// Splits a point pair T back into it's two points, found as
+    /// (T ± sqrt(T^2)) (e∞ >> T).
+    ///
+    /// Returns None if the pair is imaginary or at infinity.
+    pub fn split_pair(&self, pair: &Multivector) -> Option<(Vec<f64>, Vec<f64>)> {
+        let sqr = (pair * pair).take_grade(0).components().first().map_or(0.0, |x| x.mag);
+        if sqr < 0.0 {
+            return None;
+        }
+        let root = sqr.sqrt();
+        let proj = self.einf() << pair;
+        let first = (pair + root) * &proj;
+        let second = (pair - root) * &proj;
+        Some((self.down(&first)?, self.down(&second)?))
+    }
+
+    /// # Dual
+    ///
+    /// The dual of mv, mv I^-1. Moves from the direct form of an object to
+    /// it's dual form.
+    pub fn dual(&self, mv: &Multivector) -> Multivector {
+        mv * self.pseudoscalar().inverse().expect("CGA pseudoscalar is always invertable.")
+    }
+
+    /// # Undual
+    ///
+    /// Undoes dual, mv I.
+    pub fn undual(&self, mv: &Multivector) -> Multivector {
+        mv * self.pseudoscalar()
+    }
+
+    /// # Meet
+    ///
+    /// The intersection of two objects in their direct form, found by taking
+    /// the outer product of their dual forms. The result is in direct form.
+    pub fn meet(&self, lhs: &Multivector, rhs: &Multivector) -> Multivector {
+        self.undual(&(self.dual(lhs) ^ self.dual(rhs)))
+    }
+
+    /// # Translator
+    ///
+    /// The versor which moves everything by offset t, 1 - t e∞/2.
+    pub fn translator(&self, offset: &[f64]) -> Result<Versor, String> {
+        self.check_coords(offset)?;
+        let generator = self.euclidean(offset) * &self.einf();
+        Versor::new(&(generator * -0.5 + 1.0))
+    }
+
+    /// # Dilator
+    ///
+    /// The versor which scales everything about the origin by scale,
+    /// exp(ln(s) E/2), where E = e0 ^ e∞.
+    ///
+    /// Returns an error if the scale is not positive.
+    pub fn dilator(&self, scale: f64) -> Result<Versor, String> {
+        if scale <= 0.0 {
+            return Err(format!("Cannot dilate by {}, scale must be positive.", scale));
+        }
+        let generator = self.e0() ^ self.einf();
+        Versor::new(&(generator * (scale.ln() / 2.0)).exp())
+    }
+
+    /// # Inversion
+    ///
+    /// The versor which inverts everything in the sphere with the given
+    /// center and radius. This is the dual sphere itself, as an odd versor.
+    pub fn inversion(&self, center: &[f64], radius: f64) -> Result<Versor, String> {
+        if radius == 0.0 {
+            return Err(String::from("Cannot invert in a sphere with no radius."));
+        }
+        Versor::new(&self.sphere(center, radius)?)
+    }
+}
+
+/// # Dot
+///
+/// The scalar part of the left contraction of two multivectors, the usual
+/// dot product for vectors.
+pub fn dot(lhs: &Multivector, rhs: &Multivector) -> f64 {
+    (lhs << rhs).take_grade(0).components().first().map_or(0.0, |x| x.mag)
+}
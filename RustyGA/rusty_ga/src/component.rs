@@ -1,9 +1,14 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::ops;
+use std::str::FromStr;
 
-use regex::Regex;
+use crate::{basis::ONBasis, blade::Blade, literal::{self, ParseError}, multivector::Multivector, scalar::Scalar, vector::Vector};
 
-use crate::{basis::ONBasis, blade::Blade, multivector::Multivector, vector::Vector};
+/// # Zero Component
+///
+/// Module level shorthand for Component::ZERO.
+pub const ZERO: Component = Component::ZERO;
 
 /// # Component
 ///
@@ -14,12 +19,14 @@ use crate::{basis::ONBasis, blade::Blade, multivector::Multivector, vector::Vect
 ///
 /// Contains 2 part of data. The magnitude (mag) and the bases.
 /// Magnitude * Bases is the component.
+/// 
+/// The magnitude may be any Scalar, defaulting to f64.
 #[derive(Debug, Clone)]
-pub struct Component {
+pub struct Component<T: Scalar = f64> {
     /// # Magnitude
     ///
     /// The size of the component.
-    pub mag: f64,
+    pub mag: T,
     /// # Basis
     ///
     /// The basis of the component. IE the e_{bases} of the component.
@@ -28,9 +35,9 @@ pub struct Component {
 }
 
 
-impl Default for Component {
+impl<T: Scalar> Default for Component<T> {
     fn default() -> Self {
-        Self { mag: Default::default(), bases: Default::default() }
+        Self { mag: T::zero(), bases: Default::default() }
     }
 }
 
@@ -40,11 +47,43 @@ impl Component {
     /// Returns a component with a magnitude of 0.0 and no bases.
     pub const ZERO: Component = Component { mag: 0.0, bases: vec![] };
 
-    /// # Default
+    /// # Norm
+    ///
+    /// As Norm Squared, but includes the square Root.
+    ///
+    /// Remember, negative values will return Nan.
+    pub fn norm(&self) -> f64 {
+        self.scalar_product(self).sqrt()
+    }
+
+    /// # From Float
+    ///
+    /// Creates a grade 0 component from a float.
+    pub fn from_float(rhs: &f64) -> Component {
+        Component::new(*rhs, vec![])
+    }
+}
+
+impl<T: Scalar> Component<T> {
+    /// # Zero
     /// 
-    /// Returns a default (zero) component.
-    pub fn default() -> Component {
-        Component::ZERO
+    /// A component with a magnitude of zero and no bases, for any Scalar.
+    pub fn zero() -> Component<T> {
+        Component { mag: T::zero(), bases: vec![] }
+    }
+
+    /// # From Scalar
+    ///
+    /// Creates a grade 0 component from a scalar.
+    pub fn from_scalar(rhs: &T) -> Component<T> {
+        Component::new(rhs.clone(), vec![])
+    }
+
+    /// # Bases
+    ///
+    /// The bases of the component, in order.
+    pub fn bases(&self) -> &[ONBasis] {
+        &self.bases
     }
 
     /// # Grade
@@ -62,11 +101,11 @@ impl Component {
     /// 
     /// Duplicate Basis vectors in the Bases will also be consolidated and treated 
     /// as though multiplying.
-    pub fn new(mag: f64, bases: Vec<ONBasis>) -> Component {
-        if bases.len() > 0 {
+    pub fn new(mag: T, bases: Vec<ONBasis>) -> Component<T> {
+        if !bases.is_empty() {
             for idx in 0..(bases.len()-1) {
                 // if any disorder or duplicates, make then reorder bases immediately.
-                if bases[idx].unwrap() >= bases[idx+1].unwrap() {
+                if bases[idx] >= bases[idx+1] {
                     return Component {mag, bases}.reorder_bases();
                 }
             }
@@ -79,7 +118,7 @@ impl Component {
     /// A useful function for quickly copying a component into a new component.
     /// 
     /// This skips over reordering, as such, this is private to the Component.
-    fn quick_new(mag: f64, bases: Vec<ONBasis>) -> Component {
+    fn quick_new(mag: T, bases: Vec<ONBasis>) -> Component<T> {
         Component {mag, bases}
     }
 
@@ -89,8 +128,8 @@ impl Component {
     /// properly. Deals with duplicates as well by multiplying them to get their result.
     /// 
     /// This can return ZERO, if duplicated basis multiply to 0.
-    fn reorder_bases(&self) -> Component {
-        let mut result = Component { mag: self.mag, bases: self.bases.clone() };
+    fn reorder_bases(&self) -> Component<T> {
+        let mut result = self.clone();
 
         // loop until no movement.
         loop {
@@ -102,10 +141,10 @@ impl Component {
                     let basis = result.bases.remove(current);
                     result.bases.remove(current);
                     // Note: e_11 == e_1 . e_1 == e_1.norm()^2
-                    result.mag *= basis.sqr();
+                    result.mag = result.mag * T::from_f64(basis.sqr());
                     // If duplicate basis are degenerate (square to 0) return ZERO and move on.
-                    if result.mag == 0.0 {
-                        return Component::ZERO;
+                    if result.mag.is_zero() {
+                        return Component::zero();
                     }
                     change = true;
                     // if after the removal we would step out of the list, get out of the current loop.
@@ -116,7 +155,7 @@ impl Component {
                 // swap them if the two are out of order.
                 if result.bases[current] > result.bases[current+1] {
                     result.bases.swap(current, current+1);
-                    result.mag *= -1.0;
+                    result.mag = -result.mag;
                     change = true;
                 }
                 current += 1; // end by stepping up.
@@ -137,7 +176,7 @@ impl Component {
     ///
     /// If components don't add at all, it returns None. If they do add
     /// it returns the result.
-    pub fn force_comp_add(&self, rhs: &Component) -> Option<Component> {
+    pub fn force_comp_add(&self, rhs: &Component<T>) -> Option<Component<T>> {
         // if the same length, organize their bases
         if self.bases.len() == rhs.bases.len() {
             // if same length, check that each basis is the same
@@ -147,9 +186,9 @@ impl Component {
                 }
             }
             // if bases match, then add magnitudes
-            let res = Component::quick_new(self.mag + rhs.mag, self.bases.clone());
+            let res = Component::quick_new(self.mag.clone() + rhs.mag.clone(), self.bases.clone());
             // if magnitude is zero, then return zero component for simplicity reasons.
-            return if res.mag == 0.0 { Some(Component::ZERO) } else { Some(res) };
+            if res.mag.is_zero() { Some(Component::zero()) } else { Some(res) }
         } else {
             None
         }
@@ -161,16 +200,16 @@ impl Component {
     ///
     /// If the components share the same basis, the resulting Multivector
     /// will only have a singlular component.
-    pub fn std_comp_add(&self, rhs: &Component) -> Multivector {
-        let temp: Multivector = self.to_mv();
+    pub fn std_comp_add(&self, rhs: &Component<T>) -> Multivector<T> {
+        let temp: Multivector<T> = self.to_mv();
         temp.component_add(rhs)
     }
 
     /// # Scalar Multiplication
     ///
     /// Multiplies the component by a scalar.
-    pub fn scalar_mult(&self, rhs: f64) -> Component {
-        Component::quick_new(self.mag * rhs, self.bases.clone())
+    pub fn scalar_mult(&self, rhs: T) -> Component<T> {
+        Component::quick_new(self.mag.clone() * rhs, self.bases.clone())
     }
 
     /// # Geometric Product
@@ -181,30 +220,30 @@ impl Component {
     /// those are applied.
     ///
     /// If any part comes out to 0, then it shortcuts out.
-    pub fn geo_product(&self, rhs: &Component) -> Component {
-        let res_mag = self.mag * rhs.mag;
+    pub fn geo_product(&self, rhs: &Component<T>) -> Component<T> {
+        let res_mag = self.mag.clone() * rhs.mag.clone();
         //println!("Product Magnitude: {:?}", res_mag);
-        if res_mag == 0.0 {
+        if res_mag.is_zero() {
             // if zero, just return zero.
-            return Component::ZERO;
+            return Component::zero();
         }
         // combine bases first
         let mut bases = self.bases.clone();
         bases.extend(rhs.bases.clone());
         //println!("Bases: {:?}", bases);
         // get component
-        Component::new(self.mag*rhs.mag, bases)
+        Component::new(res_mag, bases)
     }
 
     /// # Outer Product
     ///
     /// Takes the outer product of two components. Components which share a
     /// basis produce a zero.
-    pub fn outer_product(&self, rhs: &Component) -> Component {
+    pub fn outer_product(&self, rhs: &Component<T>) -> Component<T> {
         // check for magnitudes multiplying to zero.
-        let mag = self.mag * rhs.mag;
-        if mag == 0.0 {
-            return Component::ZERO;
+        let mag = self.mag.clone() * rhs.mag.clone();
+        if mag.is_zero() {
+            return Component::zero();
         };
         // check for overlapping bases
         let mut uniques = HashSet::new();
@@ -214,25 +253,25 @@ impl Component {
         for basis in rhs.bases.iter() {
             // if any overlap, return ZERO.
             if !uniques.insert(basis) {
-                return Component::ZERO;
+                return Component::zero();
             }
         }
         // if we get here, then the all basis are unique.
-        let mut bases = vec![];
+        let mut bases = self.bases.clone();
         bases.extend(rhs.bases.clone());
-        Component { mag, bases }
+        Component::new(mag, bases)
     }
 
     /// # Reversion
     ///
     /// Produces the reverse of a blade in the grade ordering of
     /// ++--++--...
-    pub fn reversion(&self) -> Component {
+    pub fn reversion(&self) -> Component<T> {
         let grade = self.grade() / 2;
-        if grade % 2 == 0 { // 0, 1, 4, 5
-            Component::quick_new(self.mag, self.bases.clone())
+        if grade.is_multiple_of(2) { // 0, 1, 4, 5
+            Component::quick_new(self.mag.clone(), self.bases.clone())
         } else { // 2, 3, 6, 7
-            Component::quick_new(-self.mag, self.bases.clone())
+            Component::quick_new(-self.mag.clone(), self.bases.clone())
         }
     }
 
@@ -245,10 +284,10 @@ impl Component {
     ///
     /// Note: This is only meant for blades, all components are blades,
     /// but multivectors or other k-vectors may not be blades.
-    pub fn scalar_product(&self, rhs: &Component) -> f64 {
+    pub fn scalar_product(&self, rhs: &Component<T>) -> T {
         let result = self.geo_product(rhs);
         if result.grade() > 0 {
-            0.0
+            T::zero()
         } else {
             result.mag
         }
@@ -264,25 +303,20 @@ impl Component {
     /// This may be reworked in the future to be more
     /// efficient as it creates a new component, and applies
     /// reversion to it, just so it can drop it.
-    pub fn norm_sqrd(&self) -> f64 {
+    pub fn norm_sqrd(&self) -> T {
         self.scalar_product(&self.reversion())
     }
 
-    /// # Norm
-    ///
-    /// As Norm Squared, but includes the square Root.
-    ///
-    /// Remember, negative values will return Nan.
-    pub fn norm(&self) -> f64 {
-        self.scalar_product(self).sqrt()
-    }
-
     /// # Involution
     ///
     /// Involutes the the component based on it's grade.
     /// +-+-+-+-
-    pub fn involution(&self) -> Component {
-        Component::new(self.mag * (-1.0_f64).powf(self.grade() as f64) , self.bases.clone())
+    pub fn involution(&self) -> Component<T> {
+        if self.grade().is_multiple_of(2) {
+            self.clone()
+        } else {
+            -self
+        }
     }
 
     /// # Inverse
@@ -295,10 +329,10 @@ impl Component {
     ///
     /// Blades with Degenerate bases (bases^2 = 0) do not have
     /// an inverse value.
-    pub fn inverse(&self) -> Option<Component> {
+    pub fn inverse(&self) -> Option<Component<T>> {
         let rev = self.reversion();
         let norm = self.norm_sqrd();
-        if norm == 0.0 {
+        if norm.is_zero() {
             return None;
         }
         let result = rev / norm;
@@ -336,38 +370,35 @@ impl Component {
     /// - b>>A = 0 if b is perpendicular to all vectors in A.
     /// - The result of A>>B is perpendicular to A
     /// - The norm of A>>B is proportional to the norms of A and B and the
-    ///     cosine between A and it's projection on B.
-    pub fn left_cont(&self, rhs: &Component) -> Component {
+    ///   cosine between A and it's projection on B.
+    pub fn left_cont(&self, rhs: &Component<T>) -> Component<T> {
         // shortcircuit if self.grade > rhs.grade
         if self.grade() > rhs.grade() {
-            println!("lhs.grade > rhs.grade");
-            return Component::ZERO;
+            return Component::zero();
         }
         // Geometric Product, select grade rhs.grade - self.grade
         let result = self * rhs;
-        println!("Left Contraction Result: {:?}", result);
         if result.grade() == (rhs.grade() - self.grade()) {
             result
         } else {
-            println!("Left Contraction Grade Mismatch");
-            Component::ZERO
+            Component::zero()
         }
     }
 
     /// # Right Contraction
     ///
     /// Same as left, but reversed.
-    pub fn right_cont(&self, rhs: &Component) -> Component {
+    pub fn right_cont(&self, rhs: &Component<T>) -> Component<T> {
         // shortcircuit if rhs.grade > self.grade
         if self.grade() < rhs.grade() {
-            return  Component::ZERO;
+            return  Component::zero();
         }
         // otherwise geometric product, select grade self.grade - rhs.grade
         let result = self * rhs;
         if result.grade() == (self.grade() - rhs.grade()) {
             result
         } else {
-            Component::ZERO
+            Component::zero()
         }
     }
 
@@ -382,10 +413,10 @@ impl Component {
     /// 
     /// Note: This is only meant for blades, all components are blades,
     /// but multivectors or other k-vectors may not be blades.
-    pub fn inner_product(&self, rhs: &Component) -> Component {
+    pub fn inner_product(&self, rhs: &Component<T>) -> Component<T> {
         let result = self * rhs;
         if result.grade() > 0 {
-            Component::ZERO
+            Component::zero()
         } else {
             result
         }
@@ -417,13 +448,9 @@ impl Component {
     /// 
     /// TODO: This may be made a guarantee if i.inverse can be replaced with a reversion as the inverse of a pseudoscalar is equal to i.reversion().
     /// TODO: A more likely improvement is the inverse being replaced by the reciprocal of the the blade (our component)
-    pub fn dual(&self, i: &Component) -> Option<Component> {
+    pub fn dual(&self, i: &Component<T>) -> Option<Component<T>> {
         let result =  i.inverse();
-        if let Some(inv) = result {
-            Some(self << inv)
-        }  else {
-            None
-        }
+        result.map(|inv| self << inv)
     }
 
     /// # Undualization
@@ -439,7 +466,7 @@ impl Component {
     /// in all cases.
     /// 
     /// Returns None if I is a degenerate Pseudoscalar.
-    pub fn undual(&self, i: &Component) -> Option<Component> {
+    pub fn undual(&self, i: &Component<T>) -> Option<Component<T>> {
         if i.is_degenerate() {
             None
         } else {
@@ -455,18 +482,8 @@ impl Component {
     /// squaring to 0.
     /// 
     /// Returns None if rhs is degenerate.
-    pub fn project_onto(&self, rhs: &Component) -> Option<Component> {
-        if let Some(inv) = rhs.inverse() {
-            // println!("lhs: {:?}", self);
-            // println!("rhs: {:?}", rhs);
-            // println!("Contraction: {:?}", self << rhs);
-            // println!("Inverse: {:?}", inv);
-            // println!("Self * Inv Product: {:?}", self * &inv);
-            // println!("Rhs * Inv Product: {:?}", rhs * &inv);
-            Some((self << rhs) * inv)
-        } else {
-            None
-        }
+    pub fn project_onto(&self, rhs: &Component<T>) -> Option<Component<T>> {
+        rhs.inverse().map(|inv| (self << rhs) * inv)
     }
 
     /// # Rejection By
@@ -480,12 +497,8 @@ impl Component {
     /// Returns None if rhs is degenerate.
     /// 
     /// This often returns Zero as any overlap causes the rejection to go to 0.
-    pub fn rejection_by(&self, rhs: &Component) -> Option<Component> {
-        if let Some(inv) = rhs.inverse() {
-            Some((self ^ rhs) * inv)
-        } else {
-            None
-        }
+    pub fn rejection_by(&self, rhs: &Component<T>) -> Option<Component<T>> {
+        rhs.inverse().map(|inv| (self ^ rhs) * inv)
     }
 
     /// # Reciprocal Frame
@@ -499,16 +512,16 @@ impl Component {
     /// Returns zero if the vector selected is beyond our array.
     /// 
     /// Returns Zero if the component given is degenerate.
-    pub fn reciprocal_frame(&self, i: usize) -> Component {
+    pub fn reciprocal_frame(&self, i: usize) -> Component<T> {
         if i >= self.bases.len() {
-            Component::ZERO
+            Component::zero()
         } else if let Some(inv) = self.inverse() {
             let mut rep_bas = self.bases.clone();
             rep_bas.remove(i);
-            (-1.0_f64).powi(i as i32) * Component::new(self.mag,
-                rep_bas) << inv
+            let sign = if i.is_multiple_of(2) { T::one() } else { -T::one() };
+            Component::new(self.mag.clone() * sign, rep_bas) << inv
         } else {
-            Component::ZERO
+            Component::zero()
         }
     }
 
@@ -516,43 +529,56 @@ impl Component {
     ///
     /// Checks if two components share the exact same bases, returns false if
     /// not.
-    pub fn same_bases(&self, other: &Component) -> bool {
+    pub fn same_bases(&self, other: &Component<T>) -> bool {
         if self.bases.len() != other.bases.len() {
             return false;
         }
         for basis in self.bases.iter() {
-            if !other.bases.contains(&basis) {
+            if !other.bases.contains(basis) {
                 return false;
             }
         }
-        return true;
+        true
+    }
+
+    /// # Approximately Equal
+    ///
+    /// Checks if two components are equal to within eps, comparing the
+    /// magnitude of their difference.
+    ///
+    /// Components within eps of zero are equal whatever their bases.
+    pub fn approx_eq(&self, other: &Component<T>, eps: f64) -> bool {
+        if self.same_bases(other) {
+            return (self.mag.clone() - other.mag.clone()).magnitude() <= eps;
+        }
+        self.mag.magnitude() <= eps && other.mag.magnitude() <= eps
     }
 
-    /// # To String
+    /// # To String Named
+    /// 
+    /// Converts a Component to a string, writing any bases found in names
+    /// by their name instead.
     /// 
-    /// Converts a Component to a string.
-    pub fn to_string(&self) -> String {
+    /// IE, with x = P(1) and y = P(2), 3P(1)P(2) is written as 3xy.
+    pub fn to_string_named(&self, names: &HashMap<String, ONBasis>) -> String {
         let mut result = String::new();
         // add magnitude.
         result.push_str(self.mag.to_string().as_str());
         // add each basis
         for basis in self.bases.iter() {
-            result.push_str(basis.to_string().as_str());
+            if let Some((name, _)) = names.iter().find(|(_, b)| *b == basis) {
+                result.push_str(name);
+            } else {
+                result.push_str(basis.to_string().as_str());
+            }
         }
         result
     }
 
-    /// # From Float
-    ///
-    /// Creates a grade 0 component from a float.
-    pub fn from_float(rhs: &f64) -> Component {
-        Component::new(*rhs, vec![])
-    }
-
     /// # To Vector
     /// 
     /// Converts a Component to a vector if component is of grade 1.
-    pub fn to_vector(&self) -> Option<Vector> {
+    pub fn to_vector(&self) -> Option<Vector<T>> {
         if self.grade() == 1 {
             Some(Vector::new(&vec![self.clone()]))
         } else {
@@ -568,14 +594,13 @@ impl Component {
     /// 
     /// The first component always has the same magnitude as the component, the rest
     /// are of magnitude 1.
-    pub fn vector_decomposition(&self) -> Vec<Vector> {
+    pub fn vector_decomposition(&self) -> Vec<Vector<T>> {
         let mut result = vec![];
 
         for b in self.bases.iter() {
-            let mut current = Component::ZERO;
-            if result.len() == 0 {
-                current.mag = self.mag;
-            }
+            let mut current = Component::zero();
+            // the first vector carries the magnitude, the rest are unit vectors.
+            current.mag = if result.is_empty() { self.mag.clone() } else { T::one() };
             current.bases.push(*b);
             result.push(current.to_vector().expect("Somehow recieved a component not of grade 1."));
         }
@@ -586,11 +611,11 @@ impl Component {
     /// # To Blade
     /// 
     /// Coverts the component to a blade.
-    pub fn to_blade(&self) -> Blade {
+    pub fn to_blade(&self) -> Blade<T> {
         Blade { components: vec![self.clone()], vectors: self.vector_decomposition() }
     }
 
-    pub fn to_mv(&self) -> Multivector {
+    pub fn to_mv(&self) -> Multivector<T> {
         Multivector { components: vec![self.clone()], blades: vec![self.to_blade()] }
     }
 
@@ -598,125 +623,102 @@ impl Component {
     /// 
     /// Takes the Geometric product between a component and a Multivector with
     /// the Component on the left and multivector on the right.
-    pub fn geo_product_mv(&self, rhs: &Multivector) -> Multivector {
-        let mut result = Multivector::ZERO;
+    pub fn geo_product_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        let mut result = Multivector::zero();
         for comp in rhs.components().iter() {
             result = result + self * comp;
         }
         result
     }
 
+    /// # Left Contraction with Multivector
+    /// 
+    /// Takes the left contraction between a component and a Multivector, with
+    /// the Component on the left and multivector on the right.
+    pub fn left_cont_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        self.to_mv().left_cont_mv(rhs)
+    }
+
+    /// # Right Contraction with Multivector
+    /// 
+    /// Takes the right contraction between a component and a Multivector, with
+    /// the Component on the left and multivector on the right.
+    pub fn right_cont_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        self.to_mv().right_cont_mv(rhs)
+    }
+}
+
+impl Component {
     /// # From String
     /// 
     /// Translates a string into a Component. Returns an Err result if invalid
-    /// formatting.
+    /// formatting, pointing at the offending text.
     /// 
-    /// All of them are in the format of #.#B(id)B(id)
-    pub fn from_string(val: &String) -> Result<Component, String> {
-        let comp_regex: &str = r"(?<val>[+-]\d*[.]?\d*)"; //(?<b>(?<e>[PNZ])\((?<id>0|[1-9][0-9]*)\))";
-        let _re = Regex::new(comp_regex).unwrap();
-        //let Some(caps) = re.captures(val) else {Err};
-        // get value from the first, checking to ensure nothing is wrong.
-        if val.len() == 0 {
-            return Ok(Component::ZERO);
-        }
-        let mut whole = String::new();
-        let mut decimal_found = false;
-        let mut decimal = String::new();
-        let mut bases = String::new();
-        for c in val.chars() {
-            if c.is_ascii_digit() ||
-            c == '-' ||
-            c == '+' {
-                if !decimal_found {
-                    whole.push(c);
-                } else { // past decimal
-                    decimal.push(c);
-                }
-            } else if c == '.' { // if decimal
-                if decimal_found {
-                    return Err(String::from("Extra Decimal Found"));
-                } else {
-                    decimal_found = true;
-                }
-            } else { // if character found should be the start of the bases.
-                let prefix = if decimal.len() > 0 {
-                    whole.len() + 1 + decimal.len()
-                } else { whole.len() };
-                bases = val.clone().split_at(prefix).1.to_string();
-                break;
-            }
-        }
-        let whole_len = whole.len();
-        let num_res = whole + "." + if decimal.len() > 0 { decimal.as_str() } else { "0" };
-        let poss_number = num_res.parse::<f64>();
-        let number = if let Ok(num) = poss_number {
-            if whole_len == 0 && decimal.len() == 0 {
-                1.0
-            } else if num_res.get(0..1).unwrap() == "-" &&
-            bases.len() > 0 { // if just negative and basis, -1.0
-                -1.0
-            } else if num_res.get(0..1).unwrap() == "+" &&
-            bases.len() > 0 { // if just + and basis, 
-                1.0
-            } else {
-                num
-            }
-        } else {
-            return Err(String::from("Invalid number given."));
-        };
+    /// All of them are in the format of #.#B(id)B(id), where the number may
+    /// also be a fraction, like 1/3P(1), or use an exponent, like 1e-3P(1).
+    pub fn from_string(val: &str) -> Result<Component, ParseError> {
+        Component::from_string_named(val, &HashMap::new())
+    }
 
-        // with number gotten, break up the bases and convert them.
-        let mut split_bases = vec![];
-        for section in bases.split(")") {
-            if section == "" {
-                break; // if empty, then last basis past, gtfo
-            }
-            let basis = String::from(section) + ")";
-            let res = ONBasis::from_string(&basis);
-            if let Ok(b) = res {
-                split_bases.push(b);
-            } else if let Err(e) = res {
-                return Err(e);
-            }
-        }
-        // we've done all the work, return the result.
-        Ok(Component::new(number, split_bases))
+    /// # From String Named
+    /// 
+    /// As From String, but bases may also be given by any name in names.
+    /// 
+    /// IE, with x = P(1) and y = P(2), 3xy is read as 3P(1)P(2).
+    pub fn from_string_named(val: &str, names: &HashMap<String, ONBasis>) -> Result<Component, ParseError> {
+        literal::parse_component(val, names)
     }
 }
 
 
-impl PartialEq for Component {
+impl<T: Scalar> fmt::Display for Component<T> {
+    /// Writes a Component as a string, IE 3p1p2.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_named(&HashMap::new()))
+    }
+}
+
+impl<T: Scalar> PartialEq for Component<T> {
     fn eq(&self, other: &Self) -> bool {
         self.mag == other.mag && self.same_bases(other)
     }
 }
 
+impl<T: Scalar + FromStr> FromStr for Component<T> {
+    type Err = ParseError;
+
+    /// As From String, for any scalar which can be read from a string. IE
+    /// "1/3P(1)".parse::<Component<Rational>>() is exactly a third of P(1).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        literal::parse_component(s, &HashMap::new())
+    }
+}
+
 // scalar division
 
 // &comp / f64
-impl ops::Div<f64> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Div<T> for &Component<T> {
+    type Output = Component<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        self.scalar_mult(1.0 / rhs)
+    fn div(self, rhs: T) -> Self::Output {
+        self.scalar_mult(T::one() / rhs)
     }
 }
 
 // comp / f64
-impl ops::Div<f64> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Div<T> for Component<T> {
+    type Output = Component<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        self.scalar_mult(1.0 / rhs)
+    fn div(self, rhs: T) -> Self::Output {
+        self.scalar_mult(T::one() / rhs)
     }
 }
 
 // Left Contraction <<
 
 // real << real
-impl ops::Shl for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shl for Component<T> {
+    type Output = Component<T>;
 
     /// # Left Contraction
     ///
@@ -732,8 +734,8 @@ impl ops::Shl for Component {
 }
 
 // ref << ref
-impl ops::Shl<&Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shl<&Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
     /// # Left Contraction
     ///
@@ -743,14 +745,14 @@ impl ops::Shl<&Component> for &Component {
     /// then it returns a component of grade rhs.grade() - rhs.grade()
     ///
     /// Scalar values multiply.
-    fn shl(self, rhs: &Component) -> Self::Output {
+    fn shl(self, rhs: &Component<T>) -> Self::Output {
         self.left_cont(rhs)
     }
 }
 
 // ref << real
-impl ops::Shl<Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shl<Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
     /// # Left Contraction
     ///
@@ -760,14 +762,14 @@ impl ops::Shl<Component> for &Component {
     /// then it returns a component of grade rhs.grade() - rhs.grade()
     ///
     /// Scalar values multiply.
-    fn shl(self, rhs: Component) -> Self::Output {
+    fn shl(self, rhs: Component<T>) -> Self::Output {
         self.left_cont(&rhs)
     }
 }
 
 // real << ref
-impl ops::Shl<&Component> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shl<&Component<T>> for Component<T> {
+    type Output = Component<T>;
 
     /// # Left Contraction
     ///
@@ -777,7 +779,7 @@ impl ops::Shl<&Component> for Component {
     /// then it returns a component of grade rhs.grade() - rhs.grade()
     ///
     /// Scalar values multiply.
-    fn shl(self, rhs: &Component) -> Self::Output {
+    fn shl(self, rhs: &Component<T>) -> Self::Output {
         self.left_cont(rhs)
     }
 }
@@ -785,8 +787,8 @@ impl ops::Shl<&Component> for Component {
 // Right Contraction >>
 
 // real >> real
-impl ops::Shr for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shr for Component<T> {
+    type Output = Component<T>;
 
     /// # Right Contraction
     ///
@@ -802,8 +804,8 @@ impl ops::Shr for Component {
 }
 
 // ref >> ref
-impl ops::Shr<&Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shr<&Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
     /// # Right Contraction
     ///
@@ -813,14 +815,14 @@ impl ops::Shr<&Component> for &Component {
     /// then it returns a component of grade lhs.grade() - rhs.grade()
     ///
     /// Scalar values multiply.
-    fn shr(self, rhs: &Component) -> Self::Output {
+    fn shr(self, rhs: &Component<T>) -> Self::Output {
         self.right_cont(rhs)
     }
 }
 
 // ref >> real
-impl ops::Shr<Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shr<Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
     /// # Right Contraction
     ///
@@ -830,14 +832,14 @@ impl ops::Shr<Component> for &Component {
     /// then it returns a component of grade lhs.grade() - rhs.grade()
     ///
     /// Scalar values multiply.
-    fn shr(self, rhs: Component) -> Self::Output {
+    fn shr(self, rhs: Component<T>) -> Self::Output {
         self.right_cont(&rhs)
     }
 }
 
 // real >> ref
-impl ops::Shr<&Component> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Shr<&Component<T>> for Component<T> {
+    type Output = Component<T>;
 
     /// # Right Contraction
     ///
@@ -847,7 +849,7 @@ impl ops::Shr<&Component> for Component {
     /// then it returns a component of grade lhs.grade() - rhs.grade()
     ///
     /// Scalar values multiply.
-    fn shr(self, rhs: &Component) -> Self::Output {
+    fn shr(self, rhs: &Component<T>) -> Self::Output {
         self.right_cont(rhs)
     }
 }
@@ -855,8 +857,8 @@ impl ops::Shr<&Component> for Component {
 // Outer Product (^)
 
 // real ^ real
-impl ops::BitXor for Component {
-    type Output = Component;
+impl<T: Scalar> ops::BitXor for Component<T> {
+    type Output = Component<T>;
 
     fn bitxor(self, rhs: Self) -> Self::Output {
         self.outer_product(&rhs)
@@ -864,28 +866,28 @@ impl ops::BitXor for Component {
 }
 
 // ref ^ ref
-impl ops::BitXor<&Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::BitXor<&Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
-    fn bitxor(self, rhs: &Component) -> Self::Output {
+    fn bitxor(self, rhs: &Component<T>) -> Self::Output {
         self.outer_product(rhs)
     }
 }
 
 // ref ^ real
-impl ops::BitXor<Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::BitXor<Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
-    fn bitxor(self, rhs: Component) -> Self::Output {
+    fn bitxor(self, rhs: Component<T>) -> Self::Output {
         self.outer_product(&rhs)
     }
 }
 
 // real ^ ref
-impl ops::BitXor<&Component> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::BitXor<&Component<T>> for Component<T> {
+    type Output = Component<T>;
 
-    fn bitxor(self, rhs: &Component) -> Self::Output {
+    fn bitxor(self, rhs: &Component<T>) -> Self::Output {
         self.outer_product(rhs)
     }
 }
@@ -893,8 +895,8 @@ impl ops::BitXor<&Component> for Component {
 // Addition
 
 // real + real
-impl ops::Add for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add for Component<T> {
+    type Output = Multivector<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
         self.std_comp_add(&rhs)
@@ -902,44 +904,45 @@ impl ops::Add for Component {
 }
 
 // ref + ref
-impl ops::Add<&Component> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Component<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Component) -> Self::Output {
+    fn add(self, rhs: &Component<T>) -> Self::Output {
         self.std_comp_add(rhs)
     }
 }
 
 // ref + real
-impl ops::Add<Component> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<Component<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: Component) -> Self::Output {
+    fn add(self, rhs: Component<T>) -> Self::Output {
         self.std_comp_add(&rhs)
     }
 }
 
 // real + ref
-impl ops::Add<&Component> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Component<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Component) -> Self::Output {
+    fn add(self, rhs: &Component<T>) -> Self::Output {
         self.std_comp_add(rhs)
     }
 }
 
 // Into Multivector
-impl Into<Multivector> for Component {
-    fn into(self) -> Multivector {
-        Multivector { components: vec![self.clone()], blades: vec![self.to_blade()]}
+impl<T: Scalar> From<Component<T>> for Multivector<T> {
+    fn from(val: Component<T>) -> Self {
+        let blades = vec![val.to_blade()];
+        Multivector { components: vec![val], blades }
     }
 }
 
 // Subtraction
 
 // real - real
-impl ops::Sub for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub for Component<T> {
+    type Output = Multivector<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         self.std_comp_add(&-rhs)
@@ -947,28 +950,28 @@ impl ops::Sub for Component {
 }
 
 // ref - ref
-impl ops::Sub<&Component> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Component<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Component) -> Self::Output {
+    fn sub(self, rhs: &Component<T>) -> Self::Output {
         self.std_comp_add(&-rhs)
     }
 }
 
 // ref - real
-impl ops::Sub<Component> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<Component<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: Component) -> Self::Output {
+    fn sub(self, rhs: Component<T>) -> Self::Output {
         self.std_comp_add(&-rhs)
     }
 }
 
 // real - ref
-impl ops::Sub<&Component> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Component<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Component) -> Self::Output {
+    fn sub(self, rhs: &Component<T>) -> Self::Output {
         self.std_comp_add(&-rhs)
     }
 }
@@ -976,8 +979,8 @@ impl ops::Sub<&Component> for Component {
 // Geometric Product
 
 // real * real
-impl ops::Mul for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul for Component<T> {
+    type Output = Component<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         self.geo_product(&rhs)
@@ -985,70 +988,73 @@ impl ops::Mul for Component {
 }
 
 // ref * ref
-impl ops::Mul<&Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<&Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: &Component) -> Self::Output {
-        self.geo_product(&rhs)
+    fn mul(self, rhs: &Component<T>) -> Self::Output {
+        self.geo_product(rhs)
     }
 }
 
 // ref * real
-impl ops::Mul<Component> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<Component<T>> for &Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: Component) -> Self::Output {
+    fn mul(self, rhs: Component<T>) -> Self::Output {
         self.geo_product(&rhs)
     }
 }
 
 // real * ref
-impl ops::Mul<&Component> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<&Component<T>> for Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: &Component) -> Self::Output {
-        self.geo_product(&rhs)
+    fn mul(self, rhs: &Component<T>) -> Self::Output {
+        self.geo_product(rhs)
     }
 }
 
 // Scalar Multiplication
 
 // f64 * component
-impl ops::Mul<f64> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<T> for Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         self.scalar_mult(rhs)
     }
 }
 
 // f64 * &component
-impl ops::Mul<f64> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<T> for &Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         self.scalar_mult(rhs)
     }
 }
 
 // &f64 * component
-impl ops::Mul<&f64> for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<&T> for Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: &f64) -> Self::Output {
-        self.scalar_mult(*rhs)
+    fn mul(self, rhs: &T) -> Self::Output {
+        self.scalar_mult(rhs.clone())
     }
 }
 
 // &f64 * &component
-impl ops::Mul<&f64> for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Mul<&T> for &Component<T> {
+    type Output = Component<T>;
 
-    fn mul(self, rhs: &f64) -> Self::Output {
-        self.scalar_mult(*rhs)
+    fn mul(self, rhs: &T) -> Self::Output {
+        self.scalar_mult(rhs.clone())
     }
 }
 
+// A scalar on the left can't be generic, as we can't implement Mul for
+// every T, so these are f64 only.
+
 // Component * f64
 impl ops::Mul<Component> for f64 {
     type Output = Component;
@@ -1086,20 +1092,40 @@ impl ops::Mul<&Component> for &f64 {
 }
 
 
+// Reversion
+
+// !real
+impl<T: Scalar> ops::Not for Component<T> {
+    type Output = Component<T>;
+
+    fn not(self) -> Self::Output {
+        self.reversion()
+    }
+}
+
+// !ref
+impl<T: Scalar> ops::Not for &Component<T> {
+    type Output = Component<T>;
+
+    fn not(self) -> Self::Output {
+        self.reversion()
+    }
+}
+
 // -real
-impl ops::Neg for Component {
-    type Output = Component;
+impl<T: Scalar> ops::Neg for Component<T> {
+    type Output = Component<T>;
 
     fn neg(self) -> Self::Output {
-        Component { mag: -self.mag, bases: self.bases.clone() }
+        Component { mag: -self.mag, bases: self.bases }
     }
 }
 
 // -ref
-impl ops::Neg for &Component {
-    type Output = Component;
+impl<T: Scalar> ops::Neg for &Component<T> {
+    type Output = Component<T>;
 
     fn neg(self) -> Self::Output {
-        Component { mag: -self.mag, bases: self.bases.clone()}
+        Component { mag: -self.mag.clone(), bases: self.bases.clone() }
     }
 }
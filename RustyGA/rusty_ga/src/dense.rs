@@ -0,0 +1,495 @@
+use std::{ops, sync::Arc};
+
+use crate::{bitmask::{BitComponent, BitSignature}, blade::Geometry, multivector::Multivector};
+
+/// # Max Dense Dimension
+///
+/// The largest geometry a DenseAlgebra will build tables for. Each table has
+/// 4^n entries, so this is kept small.
+pub const MAX_DENSE_DIMENSION: usize = 8;
+
+/// # Cayley Table
+///
+/// The product of every pair of basis blades, stored as the index of the
+/// resulting blade and the sign it picks up. Entry lhs * 2^n + rhs.
+///
+/// A sign of 0 means the product is zero.
+#[derive(Debug, Clone, PartialEq)]
+struct CayleyTable {
+    entries: Vec<(usize, f64)>,
+}
+
+impl CayleyTable {
+    /// # New
+    ///
+    /// Builds the table for a product, given how to multiply two unit blades.
+    fn new(size: usize, product: impl Fn(&BitComponent, &BitComponent) -> BitComponent) -> CayleyTable {
+        let mut entries = Vec::with_capacity(size * size);
+        for lhs in 0..size {
+            for rhs in 0..size {
+                let result = product(
+                    &BitComponent::new(1.0, lhs as u64),
+                    &BitComponent::new(1.0, rhs as u64));
+                entries.push((result.mask as usize, result.mag));
+            }
+        }
+        CayleyTable { entries }
+    }
+}
+
+/// # Dense Algebra
+///
+/// The precomputed multiplication tables of a Geometry, shared by every
+/// DenseMultivector made from it.
+///
+/// Coefficients are indexed by the bitmask of their basis blade, see
+/// BitSignature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseAlgebra {
+    geometry: Geometry,
+    signature: BitSignature,
+    geo: CayleyTable,
+    outer: CayleyTable,
+    left_cont: CayleyTable,
+    right_cont: CayleyTable,
+}
+
+impl DenseAlgebra {
+    /// # New
+    ///
+    /// Builds the tables for a geometry.
+    ///
+    /// Returns an error if the geometry has more than MAX_DENSE_DIMENSION
+    /// bases.
+    pub fn new(geometry: &Geometry) -> Result<Arc<DenseAlgebra>, String> {
+        if geometry.dimension() > MAX_DENSE_DIMENSION {
+            return Err(format!("Dense algebras support up to {} bases, {} given.",
+                MAX_DENSE_DIMENSION, geometry.dimension()));
+        }
+        let signature = geometry.bit_signature()
+            .ok_or(String::from("Geometry has too many bases for a bit signature."))?;
+        let size = 1 << geometry.dimension();
+        let geo = CayleyTable::new(size, |l, r| l.geo_product(r, &signature));
+        let outer = CayleyTable::new(size, |l, r| l.outer_product(r));
+        let left_cont = CayleyTable::new(size, |l, r| l.left_cont(r, &signature));
+        let right_cont = CayleyTable::new(size, |l, r| l.right_cont(r, &signature));
+        Ok(Arc::new(DenseAlgebra { geometry: geometry.clone(), signature, geo, outer, left_cont, right_cont }))
+    }
+
+    /// # Geometry
+    ///
+    /// The geometry the algebra was built from.
+    pub fn geometry(&self) -> &Geometry {
+        &self.geometry
+    }
+
+    /// # Size
+    ///
+    /// How many coefficients a multivector of this algebra has, IE 2^n.
+    pub fn size(&self) -> usize {
+        1 << self.geometry.dimension()
+    }
+}
+
+/// # Dense Multivector
+///
+/// A Multivector which stores all 2^n coefficients of it's algebra, zero or
+/// not. Much faster than Multivector for small algebras, like Cl(3,0,1)
+/// or Cl(4,1), as products are done by looking up the Cayley table.
+///
+/// Coefficient i belongs to the basis blade with bitmask i.
+#[derive(Debug, Clone)]
+pub struct DenseMultivector {
+    algebra: Arc<DenseAlgebra>,
+    coefficients: Vec<f64>,
+}
+
+impl DenseMultivector {
+    /// # Zero
+    ///
+    /// The zero multivector of an algebra.
+    pub fn zero(algebra: &Arc<DenseAlgebra>) -> DenseMultivector {
+        DenseMultivector { algebra: algebra.clone(), coefficients: vec![0.0; algebra.size()] }
+    }
+
+    /// # From Multivector
+    ///
+    /// Converts a sparse Multivector into the dense form.
+    ///
+    /// Returns an error if it uses a basis outside the algebra.
+    pub fn from_mv(algebra: &Arc<DenseAlgebra>, mv: &Multivector) -> Result<DenseMultivector, String> {
+        let mut result = DenseMultivector::zero(algebra);
+        for comp in mv.components() {
+            let Some(bits) = algebra.signature.to_bits(comp) else {
+                return Err(format!("'{}' uses a basis outside of the algebra.", comp));
+            };
+            result.coefficients[bits.mask as usize] += bits.mag;
+        }
+        Ok(result)
+    }
+
+    /// # To Multivector
+    ///
+    /// Converts back into a sparse Multivector, dropping zero coefficients.
+    pub fn to_mv(&self) -> Multivector {
+        let mut result = vec![];
+        for (mask, mag) in self.coefficients.iter().enumerate() {
+            if *mag != 0.0 {
+                result.push(self.algebra.signature.from_bits(&BitComponent::new(*mag, mask as u64)));
+            }
+        }
+        Multivector::new(result)
+    }
+
+    /// # Algebra
+    ///
+    /// The algebra this multivector belongs to.
+    pub fn algebra(&self) -> &Arc<DenseAlgebra> {
+        &self.algebra
+    }
+
+    /// # Coefficients
+    ///
+    /// All coefficients, indexed by basis blade bitmask.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// # Same Algebra
+    ///
+    /// Checks that two dense multivectors can be combined.
+    fn check(&self, rhs: &DenseMultivector) -> Result<(), String> {
+        if Arc::ptr_eq(&self.algebra, &rhs.algebra) || self.algebra.geometry == rhs.algebra.geometry {
+            Ok(())
+        } else {
+            Err(String::from("Dense multivectors come from different algebras."))
+        }
+    }
+
+    /// # Product
+    ///
+    /// Multiplies two dense multivectors through a Cayley table.
+    fn product(&self, rhs: &DenseMultivector, table: &CayleyTable) -> Result<DenseMultivector, String> {
+        self.check(rhs)?;
+        let size = self.coefficients.len();
+        let mut result = DenseMultivector::zero(&self.algebra);
+        for (lidx, lhs) in self.coefficients.iter().enumerate() {
+            if *lhs == 0.0 {
+                continue;
+            }
+            for (ridx, rhs) in rhs.coefficients.iter().enumerate() {
+                let (idx, sign) = table.entries[lidx * size + ridx];
+                if *rhs != 0.0 && sign != 0.0 {
+                    result.coefficients[idx] += sign * lhs * rhs;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// # Add
+    ///
+    /// Adds two dense multivectors together.
+    pub fn add(&self, rhs: &DenseMultivector) -> Result<DenseMultivector, String> {
+        self.check(rhs)?;
+        let mut result = self.clone();
+        for (lhs, rhs) in result.coefficients.iter_mut().zip(rhs.coefficients.iter()) {
+            *lhs += rhs;
+        }
+        Ok(result)
+    }
+
+    /// # Subtract
+    ///
+    /// Subtracts rhs from self.
+    pub fn sub(&self, rhs: &DenseMultivector) -> Result<DenseMultivector, String> {
+        self.add(&rhs.scalar_mult(-1.0))
+    }
+
+    /// # Scalar Multiplication
+    ///
+    /// Multiplies every coefficient by a scalar.
+    pub fn scalar_mult(&self, rhs: f64) -> DenseMultivector {
+        let mut result = self.clone();
+        result.coefficients.iter_mut().for_each(|x| *x *= rhs);
+        result
+    }
+
+    /// # Geometric Product
+    ///
+    /// Geometric Product between two dense multivectors.
+    pub fn geo_product(&self, rhs: &DenseMultivector) -> Result<DenseMultivector, String> {
+        self.product(rhs, &self.algebra.geo)
+    }
+
+    /// # Outer Product
+    ///
+    /// Outer Product between two dense multivectors.
+    pub fn outer_product(&self, rhs: &DenseMultivector) -> Result<DenseMultivector, String> {
+        self.product(rhs, &self.algebra.outer)
+    }
+
+    /// # Left Contraction
+    ///
+    /// Left Contraction between two dense multivectors. Removes lhs from rhs.
+    pub fn left_cont(&self, rhs: &DenseMultivector) -> Result<DenseMultivector, String> {
+        self.product(rhs, &self.algebra.left_cont)
+    }
+
+    /// # Right Contraction
+    ///
+    /// Right Contraction between two dense multivectors. Removes rhs from lhs.
+    pub fn right_cont(&self, rhs: &DenseMultivector) -> Result<DenseMultivector, String> {
+        self.product(rhs, &self.algebra.right_cont)
+    }
+}
+
+// Equality
+impl PartialEq for DenseMultivector {
+    fn eq(&self, other: &Self) -> bool {
+        self.check(other).is_ok() && self.coefficients == other.coefficients
+    }
+}
+
+/// The panic message for operators on mixed algebras.
+const MIXED_ALGEBRAS: &str = "Dense multivectors come from different algebras.";
+
+// Addition
+// dense + dense
+impl ops::Add for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        DenseMultivector::add(&self, &rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// dense + &dense
+impl ops::Add<&DenseMultivector> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn add(self, rhs: &DenseMultivector) -> Self::Output {
+        DenseMultivector::add(&self, rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense + dense
+impl ops::Add<DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn add(self, rhs: DenseMultivector) -> Self::Output {
+        DenseMultivector::add(self, &rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense + &dense
+impl ops::Add<&DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn add(self, rhs: &DenseMultivector) -> Self::Output {
+        DenseMultivector::add(self, rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+
+// Subtraction
+// dense - dense
+impl ops::Sub for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        DenseMultivector::sub(&self, &rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// dense - &dense
+impl ops::Sub<&DenseMultivector> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn sub(self, rhs: &DenseMultivector) -> Self::Output {
+        DenseMultivector::sub(&self, rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense - dense
+impl ops::Sub<DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn sub(self, rhs: DenseMultivector) -> Self::Output {
+        DenseMultivector::sub(self, &rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense - &dense
+impl ops::Sub<&DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn sub(self, rhs: &DenseMultivector) -> Self::Output {
+        DenseMultivector::sub(self, rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+
+// Scalar Multiplication
+// dense * scalar
+impl ops::Mul<f64> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.scalar_mult(rhs)
+    }
+}
+// &dense * scalar
+impl ops::Mul<f64> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.scalar_mult(rhs)
+    }
+}
+
+// Geometric Product
+// dense * dense
+impl ops::Mul for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.geo_product(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// dense * &dense
+impl ops::Mul<&DenseMultivector> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn mul(self, rhs: &DenseMultivector) -> Self::Output {
+        self.geo_product(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense * dense
+impl ops::Mul<DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn mul(self, rhs: DenseMultivector) -> Self::Output {
+        self.geo_product(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense * &dense
+impl ops::Mul<&DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn mul(self, rhs: &DenseMultivector) -> Self::Output {
+        self.geo_product(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+
+// Outer Product
+// dense ^ dense
+impl ops::BitXor for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.outer_product(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// dense ^ &dense
+impl ops::BitXor<&DenseMultivector> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn bitxor(self, rhs: &DenseMultivector) -> Self::Output {
+        self.outer_product(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense ^ dense
+impl ops::BitXor<DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn bitxor(self, rhs: DenseMultivector) -> Self::Output {
+        self.outer_product(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense ^ &dense
+impl ops::BitXor<&DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn bitxor(self, rhs: &DenseMultivector) -> Self::Output {
+        self.outer_product(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+
+// Left Contraction
+// dense << dense
+impl ops::Shl for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.left_cont(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// dense << &dense
+impl ops::Shl<&DenseMultivector> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shl(self, rhs: &DenseMultivector) -> Self::Output {
+        self.left_cont(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense << dense
+impl ops::Shl<DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shl(self, rhs: DenseMultivector) -> Self::Output {
+        self.left_cont(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense << &dense
+impl ops::Shl<&DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shl(self, rhs: &DenseMultivector) -> Self::Output {
+        self.left_cont(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+
+// Right Contraction
+// dense >> dense
+impl ops::Shr for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.right_cont(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// dense >> &dense
+impl ops::Shr<&DenseMultivector> for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shr(self, rhs: &DenseMultivector) -> Self::Output {
+        self.right_cont(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense >> dense
+impl ops::Shr<DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shr(self, rhs: DenseMultivector) -> Self::Output {
+        self.right_cont(&rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+// &dense >> &dense
+impl ops::Shr<&DenseMultivector> for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn shr(self, rhs: &DenseMultivector) -> Self::Output {
+        self.right_cont(rhs).expect(MIXED_ALGEBRAS)
+    }
+}
+
+// Negation
+// -dense
+impl ops::Neg for DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn neg(self) -> Self::Output {
+        self.scalar_mult(-1.0)
+    }
+}
+// -&dense
+impl ops::Neg for &DenseMultivector {
+    type Output = DenseMultivector;
+
+    fn neg(self) -> Self::Output {
+        self.scalar_mult(-1.0)
+    }
+}
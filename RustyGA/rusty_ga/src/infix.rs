@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use crate::{basis::ONBasis, interpreter::Token};
+
+/// # Operators
+///
+/// The symbols which may be written between values in infix, longest first
+/// so >> is not read as two >.
+const OPERATORS: [&str; 17] = [
+    "==", "!=", "<=", ">=", ">>", "<<",
+    "=", "+", "-", "*", "/", "^", "~", "<", ">", "&", "|",
+];
+
+/// # Piece
+///
+/// A piece of an infix line.
+enum Piece {
+    /// A value or operation, shared with RPN.
+    Token(Token),
+    Open,
+    Close,
+    Comma,
+}
+
+/// # To RPN
+///
+/// Rearranges an infix line into the Tokens of the same line in RPN, so it
+/// can be run on the stack as usual.
+///
+/// Binary operations follow Token::precedence, so a + b * c ^ d is
+/// a + (b * (c ^ d)), and all but = group to the left. ~ and - may also
+/// negate a single value, binding tighter than any binary operation.
+/// Operations with an arity are called like functions, IE reverse(a) or
+/// dual(a, I), and parentheses group as usual.
+///
+/// Literals are as in RPN, so 3P(1)P(2), 1/3P(1), and 1e-3x are each one
+/// value.
+pub fn to_rpn(line: &str, names: &HashMap<String, ONBasis>) -> Result<Vec<Token>, String> {
+    let mut pieces = split(line, names)?;
+    // reversed, so the next piece can be popped off the end.
+    pieces.reverse();
+    let mut parser = Parser { pieces, output: vec![] };
+    if parser.pieces.is_empty() {
+        return Ok(vec![]);
+    }
+    parser.expr(0)?;
+    match parser.pieces.pop() {
+        None => Ok(parser.output),
+        Some((Piece::Close, pos)) => Err(format!("Unmatched ')' at {}.", pos)),
+        Some((_, pos)) => Err(format!("Expected an operation at {}.", pos)),
+    }
+}
+
+/// # Split
+///
+/// Breaks an infix line into pieces, along with the byte each starts at.
+fn split(line: &str, names: &HashMap<String, ONBasis>) -> Result<Vec<(Piece, usize)>, String> {
+    let mut result = vec![];
+    let mut pos = 0;
+    while pos < line.len() {
+        let rest = &line[pos..];
+        let next = rest.chars().next().unwrap_or(' ');
+        if next.is_whitespace() {
+            pos += next.len_utf8();
+            continue;
+        }
+        let (piece, len) = match next {
+            '(' => (Piece::Open, 1),
+            ')' => (Piece::Close, 1),
+            ',' => (Piece::Comma, 1),
+            _ => match OPERATORS.iter().find(|x| rest.starts_with(**x)) {
+                Some(op) => (Piece::Token(Token::translate_string(&String::from(*op))?), op.len()),
+                None => {
+                    let len = word_len(rest);
+                    if len == 0 {
+                        return Err(format!("Unexpected '{}' at {}.", next, pos));
+                    }
+                    (Piece::Token(Token::translate_string_named(&String::from(&rest[..len]), names)?), len)
+                },
+            },
+        };
+        result.push((piece, pos));
+        pos += len;
+    }
+    Ok(result)
+}
+
+/// # Word Length
+///
+/// The length of the value or name at the start of text.
+///
+/// Words are made of letters, digits, _ and ., along with the (#) of a
+/// basis like P(1), the / of a fraction between digits like 1/3, and the
+/// sign of an exponent like 1e-3.
+fn word_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let numeric = bytes.first().is_some_and(|x| x.is_ascii_digit() || *x == b'.');
+    let mut end = 0;
+    while end < bytes.len() {
+        let prev = if end > 0 { bytes[end - 1] } else { b' ' };
+        let next = bytes.get(end + 1).copied().unwrap_or(b' ');
+        match bytes[end] {
+            x if x.is_ascii_alphanumeric() || x == b'_' || x == b'.' => end += 1,
+            b'(' if matches!(prev, b'P' | b'N' | b'Z') => {
+                let digits = bytes[end + 1..].iter().take_while(|x| x.is_ascii_digit()).count();
+                if digits == 0 || bytes.get(end + 1 + digits) != Some(&b')') {
+                    break;
+                }
+                end += digits + 2;
+            },
+            b'/' if numeric && (prev.is_ascii_digit() || prev == b'.') &&
+                (next.is_ascii_digit() || next == b'.') => end += 1,
+            b'+' | b'-' if numeric && matches!(prev, b'e' | b'E') && next.is_ascii_digit() => end += 1,
+            _ => break,
+        }
+    }
+    end
+}
+
+/// # Parser
+///
+/// Precedence climbing over the pieces of a line, writing out Tokens in RPN
+/// order as it goes.
+struct Parser {
+    /// The pieces left, in reverse order.
+    pieces: Vec<(Piece, usize)>,
+    /// The Tokens written so far.
+    output: Vec<Token>,
+}
+
+impl Parser {
+    /// # Expression
+    ///
+    /// Reads a value followed by any binary operations which bind at least
+    /// as tightly as min.
+    fn expr(&mut self, min: u8) -> Result<(), String> {
+        let start = self.output.len();
+        self.unary()?;
+        loop {
+            let Some((Piece::Token(op), pos)) = self.pieces.last() else {
+                return Ok(());
+            };
+            let pos = *pos;
+            let Some((prec, right)) = op.precedence() else {
+                return Err(format!("Expected an operation at {}, found '{}'.", pos, op.val()));
+            };
+            if prec < min {
+                return Ok(());
+            }
+            if matches!(op, Token::Assn) && !matches!(&self.output[start..], [Token::Id(_)]) {
+                return Err(format!("'{}' at {} can only assign to an Id.", op.val(), pos));
+            }
+            let Some((Piece::Token(op), _)) = self.pieces.pop() else {
+                unreachable!("Checked above.");
+            };
+            self.expr(if right { prec } else { prec + 1 })?;
+            self.output.push(op);
+        }
+    }
+
+    /// # Unary
+    ///
+    /// Reads a single value, which may be negated, grouped in parentheses,
+    /// or a function call.
+    fn unary(&mut self) -> Result<(), String> {
+        let Some((piece, pos)) = self.pieces.pop() else {
+            return Err(String::from("Expected a value, found the end of the line."));
+        };
+        match piece {
+            Piece::Token(Token::Neg | Token::Sub) => {
+                self.unary()?;
+                self.output.push(Token::Neg);
+            },
+            Piece::Open => {
+                self.expr(0)?;
+                let Some((Piece::Close, _)) = self.pieces.pop() else {
+                    return Err(format!("Unclosed '(' at {}.", pos));
+                };
+            },
+            Piece::Token(func) if func.arity().is_some() => self.call(func, pos)?,
+            Piece::Token(value @ (Token::Basis(_) | Token::Scalar(_) | Token::Component(_) |
+                Token::Multivector(_) | Token::Id(_))) => self.output.push(value),
+            Piece::Token(op) => return Err(format!("Expected a value at {}, found '{}'.", pos, op.val())),
+            Piece::Close => return Err(format!("Expected a value at {}, found ')'.", pos)),
+            Piece::Comma => return Err(format!("Expected a value at {}, found ','.", pos)),
+        }
+        Ok(())
+    }
+
+    /// # Call
+    ///
+    /// Reads the arguments of a function, like the (a, I) of dual(a, I).
+    fn call(&mut self, func: Token, pos: usize) -> Result<(), String> {
+        match self.pieces.pop() {
+            Some((Piece::Open, _)) => {},
+            Some((Piece::Token(Token::Assn), _)) =>
+                return Err(format!("'{}' is a reserved word and cannot be assigned to.", func.val())),
+            _ => return Err(format!("'{}' at {} must be called like {}(...).", func.val(), pos, func.val())),
+        }
+        let arity = func.arity().unwrap_or(0);
+        let mut count = 0;
+        loop {
+            self.expr(0)?;
+            count += 1;
+            match self.pieces.pop() {
+                Some((Piece::Comma, _)) => continue,
+                Some((Piece::Close, _)) => break,
+                _ => return Err(format!("Unclosed '(' after '{}' at {}.", func.val(), pos)),
+            }
+        }
+        if count != arity {
+            return Err(format!("'{}' takes {} argument(s), but was given {}.", func.val(), arity, count));
+        }
+        self.output.push(func);
+        Ok(())
+    }
+}
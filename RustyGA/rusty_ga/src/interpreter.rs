@@ -1,6 +1,8 @@
-use std::collections::{HashMap, VecDeque};
+use std::{collections::{HashMap, VecDeque}, sync::OnceLock};
 
-use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+use regex::Regex;
+
+use crate::{basis::ONBasis, component::Component, infix, multivector::Multivector};
 
 /// # Translator
 /// 
@@ -8,7 +10,7 @@ use crate::{basis::ONBasis, component::Component, multivector::Multivector};
 /// 
 /// May eventually be upgraded to a full interpreter.
 /// 
-/// The system works by a Mixed RPN.
+/// The system works by a Mixed RPN, or by infix once switched to it, see Mode.
 /// 
 /// Put spaces between different major operations and items, leave spaces out for 
 /// those things which are part of the same thing.
@@ -22,36 +24,357 @@ pub struct Interpreter {
     // todo: Add spot for sets and strings here probably.
     /// The working stack for any operations, cleared after every line.
     pub stack: Vec<Multivector>,
+    /// The Id each value on the stack came from, if any. Kept in step with 
+    /// the stack.
+    pub ids: Vec<Option<String>>,
     /// The previous lines processed, capped to 1,000 lines.
     pub priors: VecDeque<String>,
+    /// How lines are read, postfix or infix.
+    pub mode: Mode,
+}
+
+/// # Mode
+/// 
+/// The notation lines are read in. Switched for the session with the line
+/// `mode rpn` or `mode infix`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Reverse Polish Notation, IE P(1) P(2) ^.
+    #[default]
+    Rpn,
+    /// Infix Notation, IE P(1) ^ P(2). See infix for the details.
+    Infix,
+}
+
+/// # Prior Cap
+/// 
+/// How many prior lines the interpreter remembers.
+pub const PRIOR_CAP: usize = 1_000;
+
+/// # Id Regex
+/// 
+/// What an Id must look like. Must start with a letter, followed by any 
+/// letters or numbers.
+pub const ID_REGEX: &str = r"^[a-zA-Z][a-zA-Z0-9]*$";
+
+/// # Is Id
+///
+/// Whether text matches ID_REGEX, compiling it only once.
+fn is_id(text: &str) -> bool {
+    static ID: OnceLock<Regex> = OnceLock::new();
+    ID.get_or_init(|| Regex::new(ID_REGEX).unwrap()).is_match(text)
 }
 
 impl Interpreter {
-    pub fn parse(&mut self, text: &String) -> Result<String, String> {
-        for line in text.split([';', '\n']) {
-            match self.parse_line(&String::from(line)) {
-                Ok(res) => println!("{}", res),
-                Err(exception) => {
-                    if exception == "q" { // line response is actually crash/stop worthy
-                        return Err(exception);
-                    } else { // normal error, send message so user can correct.
-                        return Ok(exception);
+    /// # New
+    /// 
+    /// Creates a new Interpreter with nothing stored.
+    pub fn new() -> Interpreter {
+        Interpreter { 
+            bases: HashMap::new(), 
+            vars: HashMap::new(), 
+            stack: vec![], 
+            ids: vec![],
+            priors: VecDeque::new(),
+            mode: Mode::Rpn,
+        }
+    }
+
+    /// # Parse
+    /// 
+    /// Evaluates any number of lines, split on newlines and ';', printing the
+    /// result of each as it goes.
+    /// 
+    /// Stops at the first line which fails, returning it's error. When the 
+    /// text has more than one line, the error starts with the line number it 
+    /// came from, IE "Line 3: 'x' has not been assigned a value."
+    pub fn parse(&mut self, text: &str) -> Result<String, String> {
+        let multiline = text.lines().count() > 1;
+        for (idx, line) in text.lines().enumerate() {
+            for statement in line.split(';') {
+                match self.parse_line(statement) {
+                    Ok(res) => if !res.is_empty() { println!("{}", res) },
+                    Err(exception) if multiline => return Err(format!("Line {}: {}", idx + 1, exception)),
+                    Err(exception) => return Err(exception),
+                }
+            }
+        }
+        Ok(String::new())
+    }
+
+    /// # Parse Line
+    /// 
+    /// Evaluates a single line, in the interpreter's current mode.
+    /// 
+    /// In RPN, each whitespace separated word is turned into a Token. Values 
+    /// are pushed onto the stack, operators pop their arguments off the stack
+    /// and push their result back on. In infix, the line is first rearranged
+    /// into RPN order, then run the same way.
+    /// 
+    /// The line `mode rpn` or `mode infix` switches modes instead.
+    /// 
+    /// Returns whatever is left on the stack, one value per line. The stack
+    /// is cleared afterwards, even if an error occurs.
+    pub fn parse_line(&mut self, line: &str) -> Result<String, String> {
+        // record the line, dropping the oldest if we are over our cap.
+        self.priors.push_back(String::from(line));
+        if self.priors.len() > PRIOR_CAP {
+            self.priors.pop_front();
+        }
+        self.clear_stack();
+        match line.split_ascii_whitespace().collect::<Vec<_>>()[..] {
+            ["mode", "rpn"] => { self.mode = Mode::Rpn; return Ok(String::new()); },
+            ["mode", "infix"] => { self.mode = Mode::Infix; return Ok(String::new()); },
+            _ => {},
+        }
+        let result = match self.mode {
+            Mode::Rpn => self.run_rpn(line),
+            Mode::Infix => infix::to_rpn(line, &self.bases)
+                .and_then(|tokens| tokens.into_iter().try_for_each(|token| self.apply(token))),
+        };
+        if let Err(exception) = result {
+            self.clear_stack();
+            return Err(exception);
+        }
+        // whatever remains on the stack is our result, so long as it has a value.
+        if let Some(id) = self.ids.iter().flatten().find(|x| !self.vars.contains_key(*x)) {
+            let exception = format!("'{}' has not been assigned a value.", id);
+            self.clear_stack();
+            return Err(exception);
+        }
+        let result = self.stack.iter()
+            .map(|x| x.to_string_named(&self.bases))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.clear_stack();
+        Ok(result)
+    }
+
+    /// # Run RPN
+    /// 
+    /// Applies each word of an RPN line to the stack, in order.
+    fn run_rpn(&mut self, line: &str) -> Result<(), String> {
+        let words = line.split_ascii_whitespace().collect::<Vec<_>>();
+        for (idx, word) in words.iter().enumerate() {
+            // Catch assignments to reserved words before they get run as operations.
+            if words.get(idx + 2) == Some(&Token::Assn.val()) && Token::is_reserved(word) {
+                return Err(format!("'{}' is a reserved word and cannot be assigned to.", word));
+            }
+            let token = Token::translate_string_named(&String::from(*word), &self.bases)?;
+            self.apply(token)?;
+        }
+        Ok(())
+    }
+
+    /// # Apply
+    /// 
+    /// Applies a single token to the stack.
+    fn apply(&mut self, token: Token) -> Result<(), String> {
+        let result = match token {
+            // Values
+            Token::Scalar(val) => Multivector::new(vec![Component::from_float(&val)]),
+            Token::Basis(basis) => basis.to_mv(),
+            Token::Component(comp) => Multivector::new(vec![comp]),
+            Token::Multivector(mv) => mv,
+            Token::Id(id) => {
+                // unassigned ids are kept as zero until assigned or used.
+                let val = self.vars.get(&id).cloned().unwrap_or(Multivector::ZERO);
+                self.push(val, Some(id));
+                return Ok(());
+            },
+            // Assignment
+            Token::Assn => {
+                if self.stack.len() < 2 {
+                    return Err(format!("'{}' requires an Id and a value on the stack.", token.val()));
+                }
+                let val = self.pop_value(&token)?;
+                self.stack.pop();
+                let Some(Some(id)) = self.ids.pop() else {
+                    return Err(format!("'{}' can only assign to an Id which is not a basis.", token.val()));
+                };
+                self.vars.insert(id.clone(), val.clone());
+                self.push(val, Some(id));
+                return Ok(());
+            },
+            Token::DeclareBasis => {
+                if self.stack.len() < 2 {
+                    return Err(format!("'{}' requires an Id and a basis on the stack.", token.val()));
+                }
+                let val = self.pop_value(&token)?;
+                self.stack.pop();
+                let Some(Some(id)) = self.ids.pop() else {
+                    return Err(format!("'{}' can only name an Id which is not already a basis.", token.val()));
+                };
+                if self.vars.contains_key(&id) {
+                    return Err(format!("'{}' is already a variable and cannot name a basis.", id));
+                }
+                let basis = match val.components() {
+                    [comp] if comp.grade() == 1 && comp.mag == 1.0 => comp.bases()[0],
+                    _ => return Err(format!("'{}' is not a single basis.", val.to_string_named(&self.bases))),
+                };
+                if let Some((name, _)) = self.bases.iter().find(|(_, b)| **b == basis) {
+                    return Err(format!("'{}' is already named '{}'.", basis, name));
+                }
+                self.bases.insert(id, basis);
+                basis.to_mv()
+            },
+            // Binary Operations
+            Token::Add => {
+                let (lhs, rhs) = self.pop_values_or(&token, Multivector::ZERO)?;
+                lhs + rhs
+            },
+            Token::Sub => {
+                if self.stack.len() == 1 { // single value, negate
+                    -self.pop_value(&token)?
+                } else {
+                    let (lhs, rhs) = self.pop_values(&token)?;
+                    lhs - rhs
+                }
+            },
+            Token::Prod => {
+                let (lhs, rhs) = self.pop_values_or(&token, Multivector::ZERO)?;
+                lhs * rhs
+            },
+            Token::Div => {
+                let (lhs, rhs) = self.pop_values(&token)?;
+                let Some(result) = lhs.div_mv(&rhs) else {
+                    return Err(format!("'{}' has no inverse, cannot divide by it.", rhs));
+                };
+                result
+            },
+            Token::LCont => {
+                let (lhs, rhs) = self.pop_values(&token)?;
+                lhs << rhs
+            },
+            Token::RCont => {
+                let (lhs, rhs) = self.pop_values(&token)?;
+                lhs >> rhs
+            },
+            Token::Outer => {
+                let (lhs, rhs) = self.pop_values(&token)?;
+                lhs ^ rhs
+            },
+            Token::Dual | Token::Undual => {
+                let (val, i) = self.pop_values(&token)?;
+                if i.len() != 1 {
+                    return Err(format!("'{}' is not a pseudoscalar, it must be a single component.", i));
+                }
+                let i = &i.components()[0];
+                let mut result = vec![];
+                for comp in val.components() {
+                    let dual = if let Token::Dual = token { comp.dual(i) } else { comp.undual(i) };
+                    let Some(dual) = dual else {
+                        return Err(format!("'{}' is degenerate and cannot be used as a pseudoscalar.", i));
+                    };
+                    result.push(dual);
+                }
+                Multivector::new(result)
+            },
+            // Unary Operations
+            Token::Invert => {
+                let val = self.pop_value(&token)?;
+                let Some(inv) = val.inverse() else {
+                    return Err(format!("'{}' has no inverse.", val));
+                };
+                inv
+            },
+            Token::Involute => {
+                let val = self.pop_value(&token)?;
+                val.grade_involution()
+            },
+            Token::Reverse => {
+                let val = self.pop_value(&token)?;
+                val.reverse()
+            },
+            Token::GenI => {
+                let val = self.pop_value(&token)?;
+                let mut bases: Vec<ONBasis> = vec![];
+                for comp in val.components() {
+                    for basis in comp.bases() {
+                        if !bases.contains(basis) {
+                            bases.push(*basis);
+                        }
                     }
-                },
+                }
+                bases.sort();
+                Component::new(1.0, bases).to_mv()
+            },
+            Token::Mag => {
+                let val = self.pop_value(&token)?;
+                let Some(mag) = val.norm_sqrd() else {
+                    return Err(format!("'{}' has no inverse, so no magnitude.", val));
+                };
+                Multivector::new(vec![Component::from_float(&mag)])
+            },
+            Token::Neg => -self.pop_value(&token)?,
+            _ => return Err(format!("'{}' is not currently supported.", token.val())),
+        };
+        self.push(result, None);
+        Ok(())
+    }
+
+    /// # Push
+    /// 
+    /// Pushes a value onto the stack, along with the Id it came from, if any.
+    fn push(&mut self, val: Multivector, id: Option<String>) {
+        self.stack.push(val);
+        self.ids.push(id);
+    }
+
+    /// # Clear Stack
+    /// 
+    /// Clears the stack and the Ids on it.
+    fn clear_stack(&mut self) {
+        self.stack.clear();
+        self.ids.clear();
+    }
+
+    /// # Pop Value
+    /// 
+    /// Pops the top value off the stack for the given operation.
+    /// 
+    /// Returns an error if the value is an Id which has not been assigned.
+    fn pop_value(&mut self, op: &Token) -> Result<Multivector, String> {
+        let Some(val) = self.stack.pop() else {
+            return Err(format!("'{}' requires a value on the stack.", op.val()));
+        };
+        if let Some(Some(id)) = self.ids.pop() {
+            if !self.vars.contains_key(&id) {
+                return Err(format!("'{}' has not been assigned a value.", id));
             }
         }
-        Ok(String::from("All Good"))
+        Ok(val)
     }
 
-    pub fn parse_line(&mut self, line: &String) -> Result<String, String> {
-        //let mut tokens = vec![];
-        for _token in line.split_ascii_whitespace() {
-            
-            
-            // Try to parse as multivec first
-            
+    /// # Pop Values
+    /// 
+    /// Pops the top two values off the stack for the given operation, returning
+    /// them in the order they were pushed.
+    fn pop_values(&mut self, op: &Token) -> Result<(Multivector, Multivector), String> {
+        if self.stack.len() < 2 {
+            return Err(format!("'{}' requires two values on the stack.", op.val()));
         }
-        todo!()
+        let rhs = self.pop_value(op)?;
+        let lhs = self.pop_value(op)?;
+        Ok((lhs, rhs))
+    }
+
+    /// # Pop Values Or
+    /// 
+    /// As Pop Values, but if only one value is on the stack, the default is used
+    /// as the second value.
+    fn pop_values_or(&mut self, op: &Token, default: Multivector) -> Result<(Multivector, Multivector), String> {
+        if self.stack.len() == 1 {
+            let lhs = self.pop_value(op)?;
+            return Ok((lhs, default));
+        }
+        self.pop_values(op)
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -74,6 +397,7 @@ pub enum Token {
     Dual,
     Undual,
     GenI,
+    DeclareBasis,
     Min,
     Max,
     Equals,
@@ -94,6 +418,67 @@ pub enum Token {
 }
 
 impl Token {
+    /// # Operations
+    /// 
+    /// Every Token which is an operation rather than a value.
+    pub const OPERATIONS: [Token; 27] = [
+        Token::Assn, Token::Add, Token::Sub, Token::Prod, Token::Div,
+        Token::LCont, Token::RCont, Token::Outer, Token::Invert, Token::Involute,
+        Token::SelGrade, Token::Reverse, Token::Dual, Token::Undual, Token::GenI,
+        Token::DeclareBasis, Token::Min, Token::Max, Token::Equals, Token::NotEqual, Token::LessThan,
+        Token::LessThanOrEqual, Token::GreaterThan, Token::GreaterThanOrEqual,
+        Token::Mag, Token::Neg, Token::EndStatement
+    ];
+
+    /// # Is Reserved
+    /// 
+    /// Checks whether the text is reserved by one of our operations, and so
+    /// cannot be used as an Id.
+    pub fn is_reserved(text: &str) -> bool {
+        Token::OPERATIONS.iter().any(|x| x.val() == text)
+    }
+
+    /// # Precedence
+    /// 
+    /// How tightly a binary operation binds in infix, higher binding tighter,
+    /// along with whether it groups to the right. None for anything which is 
+    /// not a binary operation.
+    /// 
+    /// Follows the usual GA order, so the outer product binds tighter than
+    /// the contractions, which bind tighter than the geometric product, 
+    /// which binds tighter than addition. IE a + b * c ^ d is 
+    /// a + (b * (c ^ d)).
+    pub const fn precedence(&self) -> Option<(u8, bool)> {
+        match *self {
+            Token::Assn => Some((1, true)),
+            Token::Max => Some((2, false)),
+            Token::Min => Some((3, false)),
+            Token::Equals | Token::NotEqual |
+            Token::LessThan | Token::LessThanOrEqual |
+            Token::GreaterThan | Token::GreaterThanOrEqual => Some((4, false)),
+            Token::Add | Token::Sub => Some((5, false)),
+            Token::Prod | Token::Div => Some((6, false)),
+            Token::LCont | Token::RCont => Some((7, false)),
+            Token::Outer => Some((8, false)),
+            _ => None,
+        }
+    }
+
+    /// # Arity
+    /// 
+    /// How many arguments an operation called like a function takes in 
+    /// infix, IE reverse(a) or dual(a, I). None for anything which is not
+    /// called like a function.
+    pub const fn arity(&self) -> Option<usize> {
+        match *self {
+            Token::Invert | Token::Involute | Token::Reverse | 
+            Token::GenI | Token::Mag => Some(1),
+            Token::SelGrade | Token::Dual | Token::Undual | 
+            Token::DeclareBasis => Some(2),
+            _ => None,
+        }
+    }
+
     pub const fn val(&self) -> &'static str {
         match *self {
             Token::Assn => "=",
@@ -104,13 +489,14 @@ impl Token {
             Token::LCont => ">>",
             Token::RCont => "<<",
             Token::Outer => "^",
-            Token::Invert => "invert",
+            Token::Invert => "inverse",
             Token::Involute => "involution",
             Token::SelGrade => "sel_grade",
             Token::Reverse => "reverse",
             Token::Dual => "dual",
             Token::Undual => "undual",
             Token::GenI => "generate_I",
+            Token::DeclareBasis => "basis",
             Token::Min => "&",
             Token::Max => "|",
             Token::Equals => "==",
@@ -140,6 +526,14 @@ impl Token {
     /// Turns text into a token. If it cannot translate into a token, it returns 
     /// an error.
     pub fn translate_string(text: &String) -> Result<Token, String>
+    {
+        Token::translate_string_named(text, &HashMap::new())
+    }
+
+    /// # Translate String Named
+    /// 
+    /// As Translate String, but values may also use any basis names given.
+    pub fn translate_string_named(text: &String, names: &HashMap<String, ONBasis>) -> Result<Token, String>
     {
         if text == "=" { // assign
             return Ok(Token::Assn);
@@ -167,6 +561,8 @@ impl Token {
             return Ok(Token::Undual);
         } else if text == "generate_I" { // Generate Pseudoscalar
             return Ok(Token::GenI);
+        } else if text == "basis" { // Declare Basis
+            return Ok(Token::DeclareBasis);
         } else if text == "&" { // Minimum
             return Ok(Token::Min);
         } else if text == "|" { // Maximum
@@ -195,7 +591,23 @@ impl Token {
         //     return Ok(Token::SelGrade);
         // }
 
-        Err(String::from(format!("Text '{}' is not a recognized token.", text)))
+        // Not an operation, so try it as a value.
+        if let Ok(basis) = ONBasis::from_string(text) {
+            return Ok(Token::Basis(basis));
+        } else if let Ok(mv) = Multivector::from_string_named(text, names) {
+            if mv.len() == 1 {
+                let comp = mv.components()[0].clone();
+                if comp.grade() == 0 {
+                    return Ok(Token::Scalar(comp.mag));
+                }
+                return Ok(Token::Component(comp));
+            }
+            return Ok(Token::Multivector(mv));
+        } else if is_id(text) {
+            return Ok(Token::Id(text.clone()));
+        }
+
+        Err(format!("Text '{}' is not a recognized token.", text))
     }
 }
 
@@ -1,12 +1,137 @@
+pub mod autodiff;
 pub mod blade;
 pub mod basis;
+pub mod bigint;
+pub mod bitmask;
+pub mod cga;
 pub mod component;
+pub mod dense;
+pub mod literal;
 pub mod multivector;
+pub mod pga;
+pub mod rational;
+pub mod scalar;
+pub mod symbolic;
 pub mod interpreter;
+pub mod infix;
 pub mod vector;
+pub mod versor;
 
 #[cfg(test)]
+#[allow(
+    clippy::assertions_on_constants, clippy::clone_on_copy, clippy::err_expect, clippy::get_first,
+    clippy::needless_borrow, clippy::nonminimal_bool, clippy::to_string_in_format_args,
+    clippy::unnecessary_owned_empty_strings, clippy::useless_vec,
+)]
 mod tests {
+    mod common {
+        use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+        /// Builds a multivector from (magnitude, bases) pairs.
+        pub fn mv(parts: Vec<(f64, Vec<ONBasis>)>) -> Multivector {
+            Multivector::new(parts.into_iter().map(|(mag, bases)| Component::new(mag, bases)).collect())
+        }
+
+        /// Checks that two lists of coordinates match to within 1e-9.
+        pub fn close_coords(lhs: &[f64], rhs: &[f64]) -> bool {
+            lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(l, r)| (l - r).abs() < 1e-9)
+        }
+    }
+
+    mod autodiff_tests {
+        mod dual_should {
+            use crate::{autodiff::Dual, scalar::{Real, Scalar}};
+
+            fn close(lhs: f64, rhs: f64) -> bool {
+                (lhs - rhs).abs() < 1e-12
+            }
+
+            #[test]
+            pub fn follow_the_usual_rules() {
+                let [x, y] = [Dual::variable(2.0, 0, 2), Dual::variable(3.0, 1, 2)];
+                // product and quotient rules
+                let prod = x.clone() * y.clone();
+                assert_eq!((prod.value(), prod.gradient(2)), (6.0, vec![3.0, 2.0]));
+                let quot = x.clone() / y.clone();
+                assert!(close(quot.partial(0), 1.0 / 3.0) && close(quot.partial(1), -2.0 / 9.0));
+                // chain rule, d(sin(xy)) = cos(xy) (y dx + x dy)
+                let sin = prod.sin();
+                assert!(close(sin.partial(0), 6f64.cos() * 3.0) && close(sin.partial(1), 6f64.cos() * 2.0));
+                let exp = (x.clone() * Dual::from_f64(0.5)).exp();
+                assert!(close(exp.partial(0), 0.5 * 1f64.exp()) && close(exp.partial(1), 0.0));
+                let angle = y.atan2(&x);
+                assert!(close(angle.partial(0), -3.0 / 13.0) && close(angle.partial(1), 2.0 / 13.0));
+            }
+
+            #[test]
+            pub fn treat_constants_as_having_no_derivative() {
+                let x = Dual::variable(2.0, 1, 3);
+                assert_eq!(x.gradient(3), vec![0.0, 1.0, 0.0]);
+                assert_eq!(Dual::one().gradient(3), vec![0.0; 3]);
+                assert_eq!((x.clone() + Dual::one()).gradient(3), vec![0.0, 1.0, 0.0]);
+                // zero with a derivative is not zero.
+                assert!(!(x.clone() - Dual::from_f64(2.0)).is_zero());
+                assert!(Dual::variable(0.0, 0, 1) * Dual::zero() == Dual::zero());
+                assert_eq!(Dual::from_f64(1.5).to_string(), "1.5");
+                assert_eq!(x.to_string(), "(2; 0, 1, 0)");
+            }
+        }
+
+        mod jacobian_should {
+            use crate::{autodiff::{gradient, jacobian, Dual}, basis::ONBasis, component::Component, multivector::Multivector};
+
+            fn rotor(angle: &Dual, plane: Vec<ONBasis>) -> Multivector<Dual> {
+                Component::new(angle.clone() * Dual::constant(-0.5), plane).to_mv().exp()
+            }
+
+            #[test]
+            pub fn differentiate_rotors() {
+                let p12 = vec![ONBasis::P(1), ONBasis::P(2)];
+                let result = jacobian(&[0.7], |x| rotor(&x[0], p12.clone()));
+                let plane = Component::new(1.0, p12.clone()).to_mv();
+                let expected = (&plane * -0.7 * 0.5).exp();
+                assert!(result.value.approx_eq(&expected, 1e-9));
+                // dR/dθ = -p1p2 R/2
+                assert!(result.partials[0].approx_eq(&(&plane * &expected * -0.5), 1e-9));
+
+                let (bases, matrix) = result.matrix();
+                assert_eq!(bases, vec![vec![], p12]);
+                assert!((matrix[0][0] + 0.35f64.sin() / 2.0).abs() < 1e-12);
+                assert!((matrix[1][0] + 0.35f64.cos() / 2.0).abs() < 1e-12);
+            }
+
+            #[test]
+            pub fn match_finite_differences() {
+                // rotate P(1) by two rotors, undoing the first with it's
+                // inverse, then compare against a target.
+                let cost = |x: &[Dual]| {
+                    let r1 = rotor(&x[0], vec![ONBasis::P(1), ONBasis::P(2)]);
+                    let r2 = rotor(&x[1], vec![ONBasis::P(2), ONBasis::P(3)]);
+                    let r = &r2 * &r1;
+                    let v = Component::new(Dual::constant(1.0), vec![ONBasis::P(1)]).to_mv();
+                    let target = Component::new(Dual::constant(1.0), vec![ONBasis::P(3)]).to_mv();
+                    let moved = &r * &v * r.inverse().unwrap();
+                    let diff = moved - target;
+                    (&diff * &diff).components().iter()
+                        .find(|x| x.grade() == 0)
+                        .map_or(Dual::constant(0.0), |x| x.mag.clone())
+                };
+                let params = [0.3, -1.1];
+                let (val, grad) = gradient(&params, cost);
+                let eval = |x: &[f64]| cost(&x.iter().map(|x| Dual::constant(*x)).collect::<Vec<_>>()).value();
+                assert!((val - eval(&params)).abs() < 1e-12);
+                assert!(grad.iter().all(|x| x.abs() > 1e-3));
+                for idx in 0..2 {
+                    let (mut up, mut down) = (params, params);
+                    up[idx] += 1e-6;
+                    down[idx] -= 1e-6;
+                    let numeric = (eval(&up) - eval(&down)) / 2e-6;
+                    assert!((grad[idx] - numeric).abs() < 1e-6, "{} vs {}", grad[idx], numeric);
+                }
+            }
+        }
+    }
+
     mod basis_tests {
 
         mod from_string_should {
@@ -53,6 +178,152 @@ mod tests {
         }
     }
 
+    mod bigint_tests {
+        mod bigint_should {
+            use crate::bigint::BigInt;
+
+            fn big(val: &str) -> BigInt {
+                val.parse().unwrap()
+            }
+
+            #[test]
+            pub fn round_trip_through_strings() {
+                for val in ["0", "7", "-7", "4294967296", "-18446744073709551617",
+                    "123456789012345678901234567890123456789"] {
+                    assert_eq!(big(val).to_string(), val);
+                }
+                assert_eq!(big("+12").to_string(), "12");
+                assert_eq!(big("-0"), BigInt::zero());
+                assert!("".parse::<BigInt>().is_err());
+                assert!("1.5".parse::<BigInt>().is_err());
+                assert!("12a".parse::<BigInt>().is_err());
+            }
+
+            #[test]
+            pub fn do_arithmetic() {
+                let a = big("123456789012345678901234567890");
+                let b = big("-987654321098765432109876543210");
+                assert_eq!((&a + &b).to_string(), "-864197532086419753208641975320");
+                assert_eq!((&a - &b).to_string(), "1111111110111111111011111111100");
+                assert_eq!((&a * &b).to_string(),
+                    "-121932631137021795226185032733622923332237463801111263526900");
+                assert_eq!(&a - &a, BigInt::zero());
+                assert_eq!(BigInt::from(2i64).pow(100).to_string(), "1267650600228229401496703205376");
+            }
+
+            #[test]
+            pub fn divide_towards_zero() {
+                let a = big("121932631137021795226185032733622923332237463801111263526900");
+                let b = big("123456789012345678901234567890");
+                assert_eq!((&a / &b).to_string(), "987654321098765432109876543210");
+                assert_eq!(&a % &b, BigInt::zero());
+                let (quot, rem) = big("-7").div_rem(&BigInt::from(2i64)).unwrap();
+                assert_eq!((quot, rem), (BigInt::from(-3i64), BigInt::from(-1i64)));
+                // large divisors, checked by multiplying back.
+                let num = BigInt::from(3i64).pow(200) + BigInt::from(12345i64);
+                let den = BigInt::from(7i64).pow(40);
+                let (quot, rem) = num.div_rem(&den).unwrap();
+                assert_eq!(&quot * &den + rem.clone(), num);
+                assert!(rem < den && !rem.is_negative());
+                assert_eq!(num.div_rem(&BigInt::zero()), None);
+            }
+
+            #[test]
+            pub fn find_gcd_and_convert_to_floats() {
+                assert_eq!(big("-12").gcd(&big("18")), BigInt::from(6i64));
+                assert_eq!(BigInt::zero().gcd(&big("-5")), BigInt::from(5i64));
+                assert_eq!(BigInt::from(2i64).pow(80).to_f64(), 2f64.powi(80));
+                assert_eq!(big("-1000").to_f64(), -1000.0);
+                // the top 64 bits are a tie, which the lowest bit breaks upwards.
+                let val = big("18446744073709553665");
+                assert_eq!(val.to_f64(), 2f64.powi(64) + 2f64.powi(12));
+                assert_eq!((-val).to_f64(), -(2f64.powi(64) + 2f64.powi(12)));
+                assert!(big("5") > big("-6") && big("-5") > big("-6"));
+            }
+        }
+    }
+
+    mod cga_tests {
+        mod cga_should {
+            use crate::{cga::{self, Cga}, multivector::Multivector, tests::common::close_coords};
+
+            #[test]
+            pub fn move_points_up_and_down() {
+                let cga = Cga::new(3).unwrap();
+                assert!((cga.e0() * cga.e0()).approx_eq(&Multivector::ZERO, 1e-9));
+                assert!((cga.einf() * cga.einf()).approx_eq(&Multivector::ZERO, 1e-9));
+                assert_eq!(cga::dot(&cga.e0(), &cga.einf()), -1.0);
+                let (a, b) = (cga.up(&[1.0, 2.0, 3.0]).unwrap(), cga.up(&[4.0, 6.0, 3.0]).unwrap());
+                assert!((&a * &a).approx_eq(&Multivector::ZERO, 1e-9));
+                assert!((cga::dot(&a, &b) + 12.5).abs() < 1e-12);
+                assert!(close_coords(&cga.down(&(&a * 3.0)).unwrap(), &[1.0, 2.0, 3.0]));
+                assert_eq!(cga.down(&cga.einf()), None);
+                assert!(cga.up(&[1.0]).is_err());
+            }
+
+            #[test]
+            pub fn build_spheres_planes_and_circles() {
+                let cga = Cga::new(3).unwrap();
+                let sphere = cga.sphere(&[1.0, 0.0, 0.0], 2.0).unwrap();
+                assert!(cga::dot(&cga.up(&[1.0, 2.0, 0.0]).unwrap(), &sphere).abs() < 1e-12);
+                assert!(cga::dot(&cga.up(&[1.0, 0.0, 0.0]).unwrap(), &sphere).abs() > 1.0);
+                let plane = cga.plane(&[0.0, 0.0, 1.0], 2.0).unwrap();
+                assert!(cga::dot(&cga.up(&[5.0, -3.0, 2.0]).unwrap(), &plane).abs() < 1e-12);
+                let circle = cga.circle(&[1.0, 0.0, 0.0], &[0.0, 1.0, 0.0], &[-1.0, 0.0, 0.0]).unwrap();
+                assert_eq!(circle.grades(), vec![3]);
+                assert!((cga.up(&[0.0, -1.0, 0.0]).unwrap() ^ &circle).approx_eq(&Multivector::ZERO, 1e-9));
+                assert!(!(cga.up(&[0.0, 0.0, 1.0]).unwrap() ^ &circle).approx_eq(&Multivector::ZERO, 1e-9));
+                let pair = cga.point_pair(&[1.0, 2.0, 3.0], &[-1.0, 0.5, 0.0]).unwrap();
+                let (first, second) = cga.split_pair(&pair).unwrap();
+                assert!(close_coords(&first, &[1.0, 2.0, 3.0]) || close_coords(&second, &[1.0, 2.0, 3.0]));
+                assert!(close_coords(&first, &[-1.0, 0.5, 0.0]) || close_coords(&second, &[-1.0, 0.5, 0.0]));
+            }
+
+            #[test]
+            pub fn intersect_through_the_dual() {
+                let cga = Cga::new(3).unwrap();
+                let (a, b) = (cga.sphere(&[0.0, 0.0, 0.0], 1.0).unwrap(), cga.sphere(&[1.0, 0.0, 0.0], 1.0).unwrap());
+                // dual circle where the two spheres meet, at x = 1/2.
+                let circle = &a ^ &b;
+                let height = 3.0_f64.sqrt() / 2.0;
+                assert!((cga.up(&[0.5, 0.0, height]).unwrap() << &circle).approx_eq(&Multivector::ZERO, 1e-9));
+                assert!(!(cga.up(&[0.5, 0.0, 0.0]).unwrap() << &circle).approx_eq(&Multivector::ZERO, 1e-9));
+                // cut it with the plane z = 0 for a point pair.
+                let floor = cga.plane(&[0.0, 0.0, 1.0], 0.0).unwrap();
+                let pair = cga.undual(&(&circle ^ &floor));
+                let (first, second) = cga.split_pair(&pair).unwrap();
+                let mut ys = [first[1], second[1]];
+                ys.sort_by(f64::total_cmp);
+                assert!(close_coords(&first[..1], &[0.5]) && close_coords(&second[..1], &[0.5]));
+                assert!(close_coords(&ys, &[-height, height]));
+                // the same through the direct forms.
+                let direct = cga.meet(&cga.undual(&circle), &cga.undual(&floor));
+                let (first, second) = cga.split_pair(&direct).unwrap();
+                assert!((first[1].abs() - height).abs() < 1e-9 && (second[1].abs() - height).abs() < 1e-9);
+                // far apart spheres give an imaginary pair.
+                let far = cga.sphere(&[5.0, 0.0, 0.0], 1.0).unwrap();
+                assert_eq!(cga.split_pair(&cga.undual(&(&a ^ &far ^ &floor))), None);
+            }
+
+            #[test]
+            pub fn transform_with_versors() {
+                let cga = Cga::new(3).unwrap();
+                let point = cga.up(&[2.0, 0.0, 1.0]).unwrap();
+                let translator = cga.translator(&[1.0, -1.0, 2.0]).unwrap();
+                assert!(close_coords(&cga.down(&translator.apply(&point)).unwrap(), &[3.0, -1.0, 3.0]));
+                let dilator = cga.dilator(3.0).unwrap();
+                assert!(close_coords(&cga.down(&dilator.apply(&point)).unwrap(), &[6.0, 0.0, 3.0]));
+                assert!(cga.dilator(-1.0).is_err());
+                let inversion = cga.inversion(&[0.0, 0.0, 0.0], 1.0).unwrap();
+                let far = cga.up(&[4.0, 0.0, 0.0]).unwrap();
+                assert!(close_coords(&cga.down(&inversion.apply(&far)).unwrap(), &[0.25, 0.0, 0.0]));
+                let inversion = cga.inversion(&[1.0, 1.0, 0.0], 2.0).unwrap();
+                let near = cga.up(&[2.0, 1.0, 0.0]).unwrap();
+                assert!(close_coords(&cga.down(&inversion.apply(&near)).unwrap(), &[5.0, 1.0, 0.0]));
+            }
+        }
+    }
+
     mod component_tests {
         mod from_string_should {
             use regex::Regex;
@@ -217,6 +488,19 @@ mod tests {
                     &String::from("1.+0")
                 ).err().expect("Negative placed incorrectly.");
             }
+
+            #[test]
+            pub fn parse_fractions() {
+                let result = Component::from_string(&String::from("1/4P(1)")).unwrap();
+                assert_eq!(result, Component::new(0.25, vec![ONBasis::P(1)]));
+                let result = Component::from_string(&String::from("-3/2")).unwrap();
+                assert_eq!(result, Component::new(-1.5, vec![]));
+                let result = Component::from_string(&String::from("1.5/.5N(1)")).unwrap();
+                assert_eq!(result, Component::new(3.0, vec![ONBasis::N(1)]));
+                Component::from_string(&String::from("1/0P(1)")).expect_err("Divide by zero.");
+                Component::from_string(&String::from("1/P(1)")).expect_err("Missing denominator.");
+                Component::from_string(&String::from("1/2/3P(1)")).expect_err("Double fraction.");
+            }
         }
 
         mod reorder_bases_should {
@@ -308,6 +592,22 @@ mod tests {
                 assert_ne!(c0, c123);
                 assert_ne!(c1, c123);
             }
+
+            #[test]
+            pub fn compare_approximately() {
+                let e_1 = ONBasis::P(1);
+                let e_2 = ONBasis::P(2);
+
+                let c1 = Component::new(1.0, vec![e_1]);
+                let c1_close = Component::new(1.0 + 1e-12, vec![e_1]);
+
+                assert_ne!(c1, c1_close);
+                assert!(c1.approx_eq(&c1_close, 1e-9));
+                assert!(!c1.approx_eq(&c1_close, 1e-15));
+                assert!(!c1.approx_eq(&Component::new(1.0, vec![e_2]), 1e-9));
+                // near zero, the bases don't matter.
+                assert!(Component::new(1e-17, vec![e_1]).approx_eq(&Component::new(-1e-17, vec![e_2]), 1e-9));
+            }
         }
 
         mod inner_product_should {
@@ -1165,6 +1465,468 @@ mod tests {
         }
     }
 
+    mod bitmask_tests {
+        mod products_should {
+            use crate::{basis::ONBasis, bitmask::BitComponent, blade::Geometry, component::Component};
+
+            #[test]
+            pub fn match_component_products() {
+                let g = Geometry::new(3, 2, 1);
+                let sig = g.bit_signature().unwrap();
+                let blades = g.basis_blades();
+                for (lidx, lhs) in blades.iter().enumerate() {
+                    for (ridx, rhs) in blades.iter().enumerate() {
+                        let lhs = lhs * (lidx as f64 + 1.0);
+                        let rhs = rhs * -(ridx as f64 + 0.5);
+                        let (lbits, rbits) = (sig.to_bits(&lhs).unwrap(), sig.to_bits(&rhs).unwrap());
+                        assert_eq!(sig.from_bits(&lbits), lhs);
+                        let geo = sig.from_bits(&lbits.geo_product(&rbits, &sig));
+                        assert_eq!(geo, lhs.geo_product(&rhs), "{:?} * {:?}", lhs, rhs);
+                        let outer = sig.from_bits(&lbits.outer_product(&rbits));
+                        assert_eq!(outer, lhs.outer_product(&rhs), "{:?} ^ {:?}", lhs, rhs);
+                        let left = sig.from_bits(&lbits.left_cont(&rbits, &sig));
+                        assert_eq!(left, lhs.left_cont(&rhs), "{:?} >> {:?}", lhs, rhs);
+                        let right = sig.from_bits(&lbits.right_cont(&rbits, &sig));
+                        assert_eq!(right, lhs.right_cont(&rhs), "{:?} << {:?}", lhs, rhs);
+                    }
+                    let bits = sig.to_bits(lhs).unwrap();
+                    assert_eq!(sig.from_bits(&bits.reversion()), lhs.reversion());
+                    assert_eq!(sig.from_bits(&bits.involution()), lhs.involution());
+                }
+            }
+
+            #[test]
+            pub fn reject_foreign_bases() {
+                let sig = Geometry::new(2, 0, 0).bit_signature().unwrap();
+                assert_eq!(sig.to_bits(&Component::new(1.0, vec![ONBasis::P(3)])), None);
+                assert_eq!(sig.to_bits(&Component::new(2.0, vec![ONBasis::P(2)])), Some(BitComponent::new(2.0, 0b10)));
+                assert_eq!(Geometry::new(40, 30, 0).bit_signature(), None);
+            }
+        }
+    }
+
+    mod blade_tests {
+        mod new_should {
+            use crate::{basis::ONBasis, blade::{self, Blade}, component::Component, vector::Vector};
+
+            #[test]
+            pub fn compute_the_outer_product() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                // (2p1 + p2) ^ (3p2 + 2p3) = 6p1p2 + 4p1p3 + 2p2p3
+                let a = Vector::new(&vec![Component::new(2.0, vec![p1]), Component::new(1.0, vec![p2])]);
+                let b = Vector::new(&vec![Component::new(3.0, vec![p2]), Component::new(2.0, vec![p3])]);
+                let blade = Blade::new(&vec![a.clone(), b.clone()]);
+                assert_eq!(blade.grade(), 2);
+                assert_eq!(blade.comp_len(), 3);
+                assert!(blade.components.contains(&Component::new(6.0, vec![p1, p2])));
+                assert!(blade.components.contains(&Component::new(4.0, vec![p1, p3])));
+                assert!(blade.components.contains(&Component::new(2.0, vec![p2, p3])));
+                assert_eq!(a.outer_product_blade(&b), blade);
+            }
+
+            #[test]
+            pub fn become_zero_when_dependent() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let a = Vector::new(&vec![Component::new(1.0, vec![p1]), Component::new(1.0, vec![p2])]);
+                let b = a.scalar_mult(-2.0);
+                assert_eq!(Blade::new(&vec![a.clone(), b]), blade::ZERO);
+                assert_eq!(Blade::new(&vec![a, Vector::ZERO]), blade::ZERO);
+            }
+
+            #[test]
+            pub fn be_one_without_vectors() {
+                let blade = Blade::new(&vec![]);
+                assert_eq!(blade.components, vec![Component::new(1.0, vec![])]);
+                assert_eq!(blade.grade(), 0);
+            }
+
+            #[test]
+            pub fn stay_consistent_through_operations() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let a = Blade::from_component(&Component::new(3.0, vec![p1, p2]));
+                let b = Blade::from_vector(&Vector::new(&vec![Component::new(1.0, vec![p3])]));
+                let c = a.outer_product(&b);
+                assert_eq!(c.components, vec![Component::new(3.0, vec![p1, p2, p3])]);
+                assert_eq!(Blade::new(&c.vectors), c);
+                let d = c.scalar_mult(2.0);
+                assert_eq!(d.components, vec![Component::new(6.0, vec![p1, p2, p3])]);
+                assert_eq!(Blade::new(&d.vectors), d);
+                assert_eq!(a.outer_product(&a), blade::ZERO);
+                let scalar = Blade::from_component(&Component::new(2.0, vec![]));
+                assert_eq!(scalar.outer_product(&a).components, vec![Component::new(6.0, vec![p1, p2])]);
+            }
+        }
+
+        mod meet_should {
+            use crate::{basis::ONBasis, blade::{self, Blade}, component::Component, vector::Vector};
+
+            fn vector(vals: &[(f64, ONBasis)]) -> Vector {
+                Vector::new(&vals.iter().map(|(mag, basis)| Component::new(*mag, vec![*basis])).collect())
+            }
+
+            #[test]
+            pub fn intersect_general_planes() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                // span(p1 + p2, p3) and span(p1, p2) share the line of p1 + p2.
+                let a = Blade::new(&vec![vector(&[(1.0, p1), (1.0, p2)]), vector(&[(1.0, p3)])]);
+                let b = Blade::new(&vec![vector(&[(2.0, p1), (1.0, p2)]), vector(&[(1.0, p2)])]);
+                let meet = a.meet(&b);
+                assert_eq!(meet.grade(), 1);
+                assert_eq!(meet.comp_len(), 2);
+                let expected = 0.5_f64.sqrt();
+                assert!(meet.components.iter().all(|x| (x.mag.abs() - expected).abs() < 1e-12));
+                assert_eq!(meet.components[0].mag, meet.components[1].mag);
+            }
+
+            #[test]
+            pub fn handle_contained_and_coincident_subspaces() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let plane = Blade::from_component(&Component::new(2.0, vec![p1, p2]));
+                // the same plane, from other vectors, with a different scale.
+                let same = Blade::new(&vec![vector(&[(1.0, p1), (1.0, p2)]), vector(&[(1.0, p1), (-1.0, p2)])]);
+                assert_eq!(plane.meet(&same), plane);
+                assert_eq!(same.meet(&plane), same);
+                let line = Blade::from_vector(&vector(&[(1.0, p1), (3.0, p2)]));
+                assert_eq!(line.meet(&plane), line);
+                assert_eq!(plane.meet(&line), line);
+                let space = Blade::from_component(&Component::new(1.0, vec![p1, p2, p3]));
+                assert_eq!(space.meet(&plane), plane);
+            }
+
+            #[test]
+            pub fn be_a_scalar_when_disjoint() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let plane = Blade::new(&vec![vector(&[(1.0, p1), (1.0, p2)]), vector(&[(1.0, p2)])]);
+                let line = Blade::from_vector(&vector(&[(1.0, p1), (1.0, p3)]));
+                assert_eq!(plane.meet(&line), Blade::new(&vec![]));
+                assert_eq!(plane.meet(&blade::ZERO), blade::ZERO);
+            }
+        }
+
+        mod join_should {
+            use crate::{basis::ONBasis, blade::{self, Blade}, component::Component, multivector::Multivector, vector::Vector};
+
+            fn vector(vals: &[(f64, ONBasis)]) -> Vector {
+                Vector::new(&vals.iter().map(|(mag, basis)| Component::new(*mag, vec![*basis])).collect())
+            }
+
+            #[test]
+            pub fn span_overlapping_planes() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let a = Blade::new(&vec![vector(&[(1.0, p1), (1.0, p2)]), vector(&[(1.0, p3)])]);
+                let b = Blade::new(&vec![vector(&[(1.0, p1)]), vector(&[(1.0, p2)])]);
+                let join = a.join(&b);
+                assert_eq!(join.grade(), 3);
+                assert_eq!(join.components, vec![Component::new(1.0, vec![p1, p2, p3])]);
+            }
+
+            #[test]
+            pub fn handle_contained_and_coincident_subspaces() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let plane = Blade::from_component(&Component::new(2.0, vec![p1, p2]));
+                let same = Blade::new(&vec![vector(&[(1.0, p1), (1.0, p2)]), vector(&[(1.0, p1), (-1.0, p2)])]);
+                assert_eq!(plane.join(&same), plane);
+                assert_eq!(same.join(&plane), same);
+                let line = Blade::from_vector(&vector(&[(1.0, p1), (3.0, p2)]));
+                assert_eq!(plane.join(&line), plane);
+                assert_eq!(line.join(&plane).components, vec![Component::new(-6.0, vec![p1, p2])]);
+            }
+
+            #[test]
+            pub fn be_the_outer_product_when_disjoint() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let plane = Blade::new(&vec![vector(&[(1.0, p1), (1.0, p2)]), vector(&[(1.0, p2)])]);
+                let line = Blade::from_vector(&vector(&[(1.0, p1), (1.0, p3)]));
+                assert_eq!(plane.join(&line), plane.outer_product(&line));
+                assert_eq!(blade::ZERO.join(&line), blade::ZERO);
+            }
+
+            #[test]
+            pub fn work_from_multivectors() {
+                let (p1, p2, p3, p4) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4));
+                let not_blade = Multivector::new(vec![Component::new(1.0, vec![p1, p2]), Component::new(1.0, vec![p3, p4])]);
+                assert_eq!(Blade::from_mv(&not_blade), None);
+                let mv = Multivector::new(vec![Component::new(2.0, vec![p1, p2]), Component::new(2.0, vec![p1, p3])]);
+                let blade = Blade::from_mv(&mv).expect("2p1p2 + 2p1p3 is a blade");
+                assert_eq!(Blade::new(&blade.vectors).components, mv.components);
+                assert_eq!(Blade::from_mv(&Multivector::ZERO), Some(blade::ZERO));
+            }
+        }
+    }
+
+    mod dense_tests {
+        mod dense_multivector_should {
+            use crate::{basis::ONBasis, blade::Geometry, component::Component, dense::{DenseAlgebra, DenseMultivector}, multivector::Multivector};
+
+            /// A multivector using every basis blade of the geometry.
+            fn full(g: &Geometry, offset: f64) -> Multivector {
+                Multivector::new(g.basis_blades().iter().enumerate()
+                    .map(|(idx, x)| x * (idx as f64 * 0.5 - offset))
+                    .collect())
+            }
+
+            #[test]
+            pub fn convert_losslessly() {
+                let g = Geometry::new(3, 0, 1);
+                let algebra = DenseAlgebra::new(&g).unwrap();
+                let mv = full(&g, 1.0 / 3.0);
+                let dense = DenseMultivector::from_mv(&algebra, &mv).unwrap();
+                assert_eq!(dense.coefficients().len(), 16);
+                assert_eq!(dense.to_mv(), mv);
+                assert_eq!(DenseMultivector::zero(&algebra).to_mv(), Multivector::ZERO);
+            }
+
+            #[test]
+            pub fn match_sparse_products() {
+                for g in [Geometry::new(3, 0, 0), Geometry::new(3, 0, 1), Geometry::new(4, 1, 0)] {
+                    let algebra = DenseAlgebra::new(&g).unwrap();
+                    let (a, b) = (full(&g, 2.0), full(&g, 3.5).reverse());
+                    let (da, db) = (DenseMultivector::from_mv(&algebra, &a).unwrap(),
+                        DenseMultivector::from_mv(&algebra, &b).unwrap());
+                    assert!((&da * &db).to_mv().approx_eq(&(&a * &b), 1e-9));
+                    assert!((&da ^ &db).to_mv().approx_eq(&(&a ^ &b), 1e-9));
+                    assert!((&da << &db).to_mv().approx_eq(&(&a << &b), 1e-9));
+                    assert!((&da >> &db).to_mv().approx_eq(&(&a >> &b), 1e-9));
+                    assert!((&da + &db).to_mv().approx_eq(&(&a + &b), 1e-9));
+                    assert!((&da - &db).to_mv().approx_eq(&(&a - &b), 1e-9));
+                    assert!((-(&da * 2.0)).to_mv().approx_eq(&(&a * -2.0), 1e-9));
+                }
+            }
+
+            #[test]
+            pub fn reject_other_algebras() {
+                let small = DenseAlgebra::new(&Geometry::new(2, 0, 0)).unwrap();
+                let other = DenseAlgebra::new(&Geometry::new(3, 0, 0)).unwrap();
+                let p3 = Component::new(1.0, vec![ONBasis::P(3)]).to_mv();
+                assert!(DenseMultivector::from_mv(&small, &p3).is_err());
+                let (a, b) = (DenseMultivector::zero(&small), DenseMultivector::zero(&other));
+                assert!(a.geo_product(&b).is_err());
+                assert!(a.add(&b).is_err());
+                assert!(DenseAlgebra::new(&Geometry::new(9, 0, 0)).is_err());
+            }
+        }
+    }
+
+    mod geometry_tests {
+        mod new_should {
+            use crate::{basis::ONBasis, blade::Geometry};
+
+            #[test]
+            pub fn build_from_signature() {
+                let g = Geometry::new(3, 1, 1);
+                assert_eq!(g.signature(), (3, 1, 1));
+                assert_eq!(g.dimension(), 5);
+                assert_eq!(g.bases(), vec![ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), 
+                    ONBasis::N(1), ONBasis::Z(0)]);
+                assert_eq!(g.name_of(&ONBasis::Z(0)), Some("z0"));
+                assert_eq!(g.basis("p2"), Some(ONBasis::P(2)));
+                assert_eq!(g.basis("q2"), None);
+            }
+
+            #[test]
+            pub fn reject_repeated_names_and_bases() {
+                Geometry::from_named(vec![
+                    (String::from("x"), ONBasis::P(1)),
+                    (String::from("x"), ONBasis::P(2)),
+                ]).expect_err("Repeated name.");
+                Geometry::from_named(vec![
+                    (String::from("x"), ONBasis::P(1)),
+                    (String::from("y"), ONBasis::P(1)),
+                ]).expect_err("Repeated basis.");
+            }
+        }
+
+        mod elements_should {
+            use crate::{basis::ONBasis, blade::Geometry, component::Component, multivector::Multivector};
+
+            #[test]
+            pub fn build_blades_and_pseudoscalar() {
+                let g = Geometry::new(3, 0, 0);
+                let blades = g.basis_blades();
+                assert_eq!(blades.len(), 8);
+                assert_eq!(blades[0], Component::new(1.0, vec![]));
+                assert_eq!(blades[1], Component::new(1.0, vec![ONBasis::P(1)]));
+                assert_eq!(blades[7], Component::new(1.0, vec![ONBasis::P(1), ONBasis::P(2), ONBasis::P(3)]));
+                assert!(blades.iter().all(|x| x.mag == 1.0));
+                assert_eq!(g.pseudoscalar(), blades[7]);
+
+                // order of the geometry carries to the pseudoscalar.
+                let g = Geometry::from_named(vec![
+                    (String::from("y"), ONBasis::P(2)),
+                    (String::from("x"), ONBasis::P(1)),
+                ]).unwrap();
+                assert_eq!(g.pseudoscalar(), Component::new(-1.0, vec![ONBasis::P(1), ONBasis::P(2)]));
+                assert_eq!(g.basis_blades()[3], g.pseudoscalar());
+
+                assert_eq!(g.zero(), Multivector::ZERO);
+                assert_eq!(g.one(), Multivector::new(vec![Component::new(1.0, vec![])]));
+            }
+
+            #[test]
+            pub fn reject_mixed_geometries() {
+                let g1 = Geometry::new(2, 0, 0);
+                let g2 = Geometry::new(0, 2, 0);
+                let a = g1.from_string(&String::from("3p1p2")).unwrap();
+                let b = g2.from_string(&String::from("n1")).unwrap();
+                assert_eq!(g1.to_string(&a), "3p1p2");
+                g1.mv((a * b).components).expect_err("Mixed geometries.");
+                g1.from_string(&String::from("n1")).expect_err("Basis from another geometry.");
+                g1.mv(vec![Component::new(2.0, vec![ONBasis::P(1)])]).unwrap();
+            }
+        }
+
+        mod zero_threshold_should {
+            use std::f64::consts::PI;
+
+            use crate::{basis::ONBasis, blade::Geometry, component::Component, multivector::Multivector, versor::Versor};
+
+            #[test]
+            pub fn clean_after_products() {
+                let g = Geometry::new(3, 0, 0);
+                let clean = g.clone().with_zero_threshold(1e-12);
+                assert_eq!(g.zero_threshold(), None);
+                assert_eq!(clean.zero_threshold(), Some(1e-12));
+
+                // a quarter turn in the p1p2 plane, done 4 times.
+                let rotor = (Component::new(-PI / 4.0, vec![ONBasis::P(1), ONBasis::P(2)]).to_mv()).exp();
+                let start = Component::new(1.0, vec![ONBasis::P(1)]).to_mv();
+                let mut noisy = start.clone();
+                let mut cleaned = start.clone();
+                for _ in 0..4 {
+                    noisy = g.sandwich(&rotor, &noisy).unwrap();
+                    cleaned = clean.sandwich(&rotor, &cleaned).unwrap();
+                }
+                assert!(noisy.len() > 1, "{}", noisy.to_string());
+                assert!(noisy.approx_eq(&start, 1e-12));
+                assert_eq!(cleaned.len(), 1);
+                assert!(cleaned.approx_eq(&start, 1e-12));
+
+                let tiny = Multivector::new(vec![Component::new(1e-17, vec![ONBasis::P(3)])]);
+                assert_eq!(clean.geo_product(&start, &(&start + &tiny)).unwrap(), Multivector::new(vec![Component::new(1.0, vec![])]));
+                assert_eq!(clean.outer_product(&start, &tiny).unwrap(), Multivector::ZERO);
+                assert_eq!(g.outer_product(&start, &tiny).unwrap().len(), 1);
+                assert_eq!(clean.left_cont(&tiny, &start).unwrap(), Multivector::ZERO);
+                assert_eq!(clean.right_cont(&start, &tiny).unwrap(), Multivector::ZERO);
+            }
+
+            #[test]
+            pub fn reflect_with_the_right_sign() {
+                let g = Geometry::new(3, 0, 0);
+                let (p1, p2) = (ONBasis::P(1).to_mv(), ONBasis::P(2).to_mv());
+                // reflecting in p1 flips the p1 part.
+                let reflected = g.sandwich(&p1, &(&p1 + &p2)).unwrap();
+                assert_eq!(reflected, &p2 - &p1);
+                assert_eq!(reflected, Versor::new(&p1).unwrap().apply(&(&p1 + &p2)));
+                g.sandwich(&(&p1 + 1.0), &p2).expect_err("Mixed grades.");
+                g.sandwich(&Multivector::ZERO, &p2).expect_err("No inverse.");
+            }
+
+            #[test]
+            pub fn reject_bases_from_other_geometries() {
+                let g = Geometry::new(2, 0, 0);
+                let (z0, p1) = (ONBasis::Z(0).to_mv(), ONBasis::P(1).to_mv());
+                g.geo_product(&z0, &p1).expect_err("z0 is not in the geometry.");
+                g.outer_product(&p1, &z0).expect_err("z0 is not in the geometry.");
+                g.left_cont(&z0, &p1).expect_err("z0 is not in the geometry.");
+                g.right_cont(&p1, &z0).expect_err("z0 is not in the geometry.");
+                g.regressive(&z0, &p1).expect_err("z0 is not in the geometry.");
+                g.sandwich(&p1, &z0).expect_err("z0 is not in the geometry.");
+                assert_eq!(g.geo_product(&p1, &p1).unwrap(), Multivector::new(vec![Component::new(1.0, vec![])]));
+            }
+        }
+    }
+
+    mod literal_tests {
+        mod parse_multivector_should {
+            use std::collections::HashMap;
+
+            use crate::{basis::ONBasis, component::Component, literal::parse_multivector, multivector::Multivector, rational::Rational, tests::common::mv};
+
+            #[test]
+            pub fn read_scientific_notation() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                assert_eq!(Multivector::from_string("1e-3P(1)").unwrap(), mv(vec![(1e-3, vec![p1])]));
+                assert_eq!(Multivector::from_string("2.5E+2P(1)-1e-1P(2)").unwrap(), 
+                    mv(vec![(250.0, vec![p1]), (-0.1, vec![p2])]));
+                assert_eq!(Multivector::from_string("1e3").unwrap(), mv(vec![(1000.0, vec![])]));
+                let exact: Multivector<Rational> = "1e-3P(1)".parse().unwrap();
+                assert_eq!(exact.components()[0].mag, Rational::from_ints(1, 1000).unwrap());
+            }
+
+            #[test]
+            pub fn ignore_whitespace() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                assert_eq!(Multivector::from_string("  3 P(1) +\t2P(2) - 1 ").unwrap(), 
+                    mv(vec![(-1.0, vec![]), (3.0, vec![p1]), (2.0, vec![p2])]));
+                assert_eq!(Multivector::from_string(" ").unwrap(), Multivector::ZERO);
+            }
+
+            #[test]
+            pub fn expand_parenthesized_sums() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                assert_eq!(Multivector::from_string("2(P(1) + P(2))P(3)").unwrap(), 
+                    mv(vec![(2.0, vec![p1, p3]), (2.0, vec![p2, p3])]));
+                assert_eq!(Multivector::from_string("-(P(1) - 1)").unwrap(), 
+                    mv(vec![(1.0, vec![]), (-1.0, vec![p1])]));
+                // groups multiply with the geometric product.
+                assert_eq!(Multivector::from_string("(1 + P(1)P(2))(1 - P(1)P(2))").unwrap(), 
+                    mv(vec![(2.0, vec![])]));
+                assert_eq!(Multivector::from_string("((P(1)))").unwrap(), ONBasis::P(1).to_mv());
+            }
+
+            #[test]
+            pub fn read_named_bases() {
+                let names = HashMap::from([
+                    (String::from("e1"), ONBasis::P(1)),
+                    (String::from("e2"), ONBasis::P(2)),
+                ]);
+                let result: Multivector = parse_multivector("2e1e2 - 1e-3e1", &names).unwrap();
+                assert_eq!(result, mv(vec![(-1e-3, vec![ONBasis::P(1)]), (2.0, vec![ONBasis::P(1), ONBasis::P(2)])]));
+                // without names, it's an exponent.
+                assert_eq!(Multivector::from_string("2e1").unwrap(), mv(vec![(20.0, vec![])]));
+            }
+
+            #[test]
+            pub fn only_read_a_name_over_an_exponent_if_it_fits_whole() {
+                let names = HashMap::from([(String::from("e"), ONBasis::P(1))]);
+                let result: Multivector = parse_multivector("2e1", &names).unwrap();
+                assert_eq!(result, mv(vec![(20.0, vec![])]));
+                let result: Multivector = parse_multivector("2e", &names).unwrap();
+                assert_eq!(result, mv(vec![(2.0, vec![ONBasis::P(1)])]));
+                let names = HashMap::from([(String::from("e1"), ONBasis::P(1))]);
+                let result: Multivector = parse_multivector("2e1", &names).unwrap();
+                assert_eq!(result, mv(vec![(2.0, vec![ONBasis::P(1)])]));
+                let result: Multivector = parse_multivector("2e12", &names).unwrap();
+                assert_eq!(result, mv(vec![(2e12, vec![])]));
+            }
+
+            #[test]
+            pub fn read_names_followed_by_groups() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let names = HashMap::from([(String::from("x"), p3)]);
+                // P(3)P(1) is -P(1)P(3).
+                let result: Multivector = parse_multivector("2x(P(1) + P(2))", &names).unwrap();
+                assert_eq!(result, mv(vec![(-2.0, vec![p1, p3]), (-2.0, vec![p2, p3])]));
+                let result: Multivector = parse_multivector("x(P(2))", &names).unwrap();
+                assert_eq!(result, mv(vec![(-1.0, vec![p2, p3])]));
+            }
+
+            #[test]
+            pub fn report_error_spans() {
+                let err = Multivector::from_string("P(1) + Q(2)").unwrap_err();
+                assert_eq!(err.span, 7..11);
+                assert_eq!(Multivector::from_string("1 + (P(1)").unwrap_err().span, 4..5);
+                assert_eq!(Multivector::from_string("P(1))").unwrap_err().span, 4..5);
+                assert_eq!(Multivector::from_string("2 1.0.0").unwrap_err().span, 2..6);
+                assert_eq!(Multivector::from_string("1/0P(1)").unwrap_err().span, 0..3);
+                assert_eq!(Multivector::from_string("P(1) +").unwrap_err().span, 6..6);
+                assert_eq!(Multivector::from_string("3 x").unwrap_err().to_string(), 
+                    "'x' does not start with a known basis. (at 2..3)");
+                Component::from_string(&String::from("P(1) + P(2)")).expect_err("Not a single component.");
+                Component::from_string(&String::from("2(P(1))")).expect_err("No groups in a component.");
+            }
+        }
+    }
     mod multivector_tests {
         mod from_string_should {
             use std::vec;
@@ -1233,6 +1995,40 @@ mod tests {
             // TODO: No invalid Tests as all of them would trickle up from lower levels.
         }
 
+        mod from_string_named_should {
+            use std::collections::HashMap;
+
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+            #[test]
+            pub fn read_and_write_named_bases() {
+                let names = HashMap::from([
+                    (String::from("x"), ONBasis::P(1)),
+                    (String::from("y"), ONBasis::P(2)),
+                    (String::from("xt"), ONBasis::N(0)),
+                ]);
+
+                let result = Multivector::from_string_named(
+                    &String::from("3xy-2P(3)x"), &names
+                ).expect("Named bases");
+                let expected = Multivector::new(vec![
+                    Component::new(3.0, vec![ONBasis::P(1), ONBasis::P(2)]),
+                    Component::new(2.0, vec![ONBasis::P(1), ONBasis::P(3)]),
+                ]);
+                assert_eq!(result, expected);
+                assert_eq!(expected.to_string_named(&names), "3xy + 2xp3");
+
+                // longest name wins.
+                let result = Component::from_string_named(&String::from("xty"), &names)
+                    .expect("Greedy names");
+                assert_eq!(result, Component::new(1.0, vec![ONBasis::N(0), ONBasis::P(2)]));
+                assert_eq!(result.to_string_named(&names), "1xty");
+
+                Component::from_string_named(&String::from("3xq"), &names)
+                    .expect_err("Unknown name");
+            }
+        }
+
         mod new_should {
             use crate::{basis::ONBasis, component::Component, multivector::Multivector};
 
@@ -1274,7 +2070,19 @@ mod tests {
                 );
                 assert!(n_component.is_blade(), "Higher Dimension Component isn't blade.");
 
+                // (p1 + p3) ^ (p2 + p4)
                 let complex_blade = Multivector::new(
+                    vec![
+                        Component::new(1.0, vec![p1, p2]),
+                        Component::new(1.0, vec![p1, p4]),
+                        Component::new(-1.0, vec![p2, p3]),
+                        Component::new(1.0, vec![p3, p4])
+                    ]
+                );
+                assert!(complex_blade.is_blade(), "Complex BLade is Not valid.");
+
+                // every component shares a basis with another, but B ^ B != 0.
+                let connected_not_blade = Multivector::new(
                     vec![
                         Component::new(1.0, vec![p1, p2]),
                         Component::new(1.0, vec![p2, p3]),
@@ -1282,7 +2090,7 @@ mod tests {
                         Component::new(1.0, vec![p4, p5])
                     ]
                 );
-                assert!(complex_blade.is_blade(), "Complex BLade is Not valid.");
+                assert!(!connected_not_blade.is_blade(), "Connected Not Blade is returning as Blade.");
 
                 let not_blade = Multivector::new(
                     vec![
@@ -1301,34 +2109,238 @@ mod tests {
                 );
                 assert!(!multigrade.is_blade(), "Multigrade has returned as blade.");
             }
-        }
-    
-        mod add_component_should {
-            use crate::{basis::ONBasis, component::{Component, self}, multivector::{self, Multivector}};
 
             #[test]
-            pub fn correctly_add_component_to_multivector() {
-                let b1 = ONBasis::P(1);
-                let b2 = ONBasis::P(2);
+            pub fn tolerate_small_errors() {
+                let (p1, p2, p3, p4) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4));
+                let noisy_blade = Multivector::new(
+                    vec![
+                        Component::new(1.0, vec![p1, p2]),
+                        Component::new(1.0, vec![p1, p4]),
+                        Component::new(-1.0, vec![p2, p3]),
+                        Component::new(1.0 + 1e-14, vec![p3, p4])
+                    ]
+                );
+                assert!(noisy_blade.is_blade());
+                let not_blade = Multivector::new(
+                    vec![
+                        Component::new(1.0, vec![p1, p2]),
+                        Component::new(1e-3, vec![p3, p4]),
+                    ]
+                );
+                assert!(!not_blade.is_blade());
+            }
+        }
 
-                let comp1 = Component::new(1.0, 
-                    vec![b1]);
-                let comp2 = Component::new(1.0, 
-                    vec![b2]);
-                let comp12 = Component::new(1.0, 
-                    vec![b1, b2]);
+        mod blade_breakdown_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector, vector::Vector};
 
-                let mv = multivector::ZERO;
+            fn rebuild(vectors: &[Vector]) -> Multivector {
+                vectors.iter().fold(Component::new(1.0, vec![]).to_mv(), 
+                    |acc, vec| acc ^ Multivector::new(vec.components.clone()))
+            }
 
-                let mv1 = mv.component_add(&comp1);
-                assert_eq!(mv1.len(), 1);
-                assert_eq!(mv1.components()[0].mag, 1.0);
-                assert_eq!(mv1.components()[0].bases(), vec![b1]);
+            #[test]
+            pub fn reproduce_the_blade() {
+                let (p1, p2, p3, p4, n1) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4), ONBasis::N(1));
+                // 6p1p2 + 2p2p3
+                let blade = Multivector::new(vec![
+                    Component::new(6.0, vec![p1, p2]),
+                    Component::new(2.0, vec![p2, p3]),
+                ]);
+                let vectors = blade.blade_breakdown().unwrap();
+                assert_eq!(vectors.len(), 2);
+                assert!(rebuild(&vectors).approx_eq(&blade, 1e-12));
+
+                // (p1 + p2) ^ (p2 - 2p3) ^ (p3 + p4 + 3n1)
+                let a = Multivector::new(vec![Component::new(1.0, vec![p1]), Component::new(1.0, vec![p2])]);
+                let b = Multivector::new(vec![Component::new(1.0, vec![p2]), Component::new(-2.0, vec![p3])]);
+                let c = Multivector::new(vec![
+                    Component::new(1.0, vec![p3]), Component::new(1.0, vec![p4]), Component::new(3.0, vec![n1])]);
+                let blade = a ^ b ^ c;
+                let vectors = blade.blade_breakdown().unwrap();
+                assert_eq!(vectors.len(), 3);
+                assert!(rebuild(&vectors).approx_eq(&blade, 1e-12));
+            }
 
-                let mv1p = mv1.component_add(&comp1);
-                assert_eq!(mv1p.len(), 1);
-                assert_eq!(mv1p.components()[0].mag, 2.0);
-                assert_eq!(mv1p.components()[0].bases(), vec![b1]);
+            #[test]
+            pub fn handle_simple_cases() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                assert_eq!(Multivector::ZERO.blade_breakdown(), None);
+                assert_eq!(Component::new(2.0, vec![]).to_mv().blade_breakdown(), Some(vec![]));
+                let comp = Multivector::new(vec![Component::new(3.0, vec![p1, p2])]);
+                let vectors = comp.blade_breakdown().unwrap();
+                assert!(rebuild(&vectors).approx_eq(&comp, 1e-12));
+                let not_blade = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![p1]),
+                ]);
+                assert_eq!(not_blade.blade_breakdown(), None);
+            }
+
+            #[test]
+            pub fn fill_multivector_blades() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(6.0, vec![p1, p2]),
+                    Component::new(2.0, vec![p2, p3]),
+                ]);
+                // only factored when asked.
+                assert_eq!(mv.blades.len(), 3);
+                let mv = mv.factor_blades();
+                assert_eq!(mv.blades.len(), 2);
+                assert_eq!(mv.blades[1].grade(), 2);
+                assert!(rebuild(&mv.blades[1].vectors).approx_eq(&mv.take_grade(2), 1e-12));
+            }
+        }
+    
+        mod regressive_should {
+            use crate::{basis::ONBasis, blade::Geometry, component::Component, multivector::Multivector};
+
+            /// A multivector using every basis blade of the geometry.
+            fn full(g: &Geometry, offset: f64) -> Multivector {
+                Multivector::new(g.basis_blades().iter().enumerate()
+                    .map(|(idx, x)| x * (idx as f64 - offset))
+                    .collect())
+            }
+
+            #[test]
+            pub fn intersect_planes() {
+                let g = Geometry::new(3, 0, 0);
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let a = Component::new(1.0, vec![p1, p2]).to_mv();
+                let b = Component::new(1.0, vec![p2, p3]).to_mv();
+                assert_eq!(a.regressive(&b, &g), p2.to_mv());
+                // planes with nothing more than the origin in common meet in a scalar.
+                let c = Component::new(2.0, vec![p3]).to_mv();
+                assert_eq!(a.regressive(&c, &g), Component::new(2.0, vec![]).to_mv());
+            }
+
+            #[test]
+            pub fn have_the_pseudoscalar_as_identity() {
+                for g in [Geometry::new(3, 0, 0), Geometry::new(2, 1, 0), Geometry::new(2, 0, 1)] {
+                    let i = g.pseudoscalar().to_mv();
+                    let a = full(&g, 3.0);
+                    assert_eq!(i.regressive(&a, &g), a);
+                    assert_eq!(a.regressive(&i, &g), a);
+                }
+            }
+
+            #[test]
+            pub fn match_complements_through_duality() {
+                for g in [Geometry::new(3, 0, 0), Geometry::new(2, 1, 0), Geometry::new(1, 3, 0)] {
+                    let (a, b) = (full(&g, 3.0), full(&g, 5.0));
+                    let complement = g.inverse_complement(&(g.complement(&a) ^ g.complement(&b)));
+                    assert_eq!(a.regressive(&b, &g), complement);
+                }
+            }
+
+            #[test]
+            pub fn work_with_degenerate_metrics() {
+                // in Cl(2,0,1) vectors are lines, and two lines meet in a point.
+                let g = Geometry::new(2, 0, 1);
+                let (z0, p1, p2) = (ONBasis::Z(0), ONBasis::P(1), ONBasis::P(2));
+                let a = Component::new(1.0, vec![z0, p1]).to_mv();
+                let b = Component::new(1.0, vec![p1, p2]).to_mv();
+                assert_eq!(a.regressive(&b, &g), p1.to_mv());
+                assert_eq!(g.inverse_complement(&g.complement(&b)), b);
+            }
+        }
+
+        mod canonical_order_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+            fn bases(mv: &Multivector) -> Vec<Vec<ONBasis>> {
+                mv.components().iter().map(|x| x.bases().to_vec()).collect()
+            }
+
+            #[test]
+            pub fn order_by_grade_then_bases() {
+                let (p1, p2, p3, n1) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::N(1));
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![p2, p3]),
+                    Component::new(1.0, vec![p3]),
+                    Component::new(1.0, vec![p1, p2]),
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![p1]),
+                    Component::new(1.0, vec![n1]),
+                    Component::new(2.0, vec![p1]),
+                ]);
+                assert_eq!(bases(&mv), vec![vec![], vec![n1], vec![p1], vec![p3], vec![p1, p2], vec![p2, p3]]);
+                assert_eq!(mv.components()[2].mag, 3.0);
+            }
+
+            #[test]
+            pub fn merge_when_adding_and_subtracting() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let lhs = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(2.0, vec![p2]),
+                    Component::new(3.0, vec![p1, p3]),
+                ]);
+                let rhs = Multivector::new(vec![
+                    Component::new(1.0, vec![p1]),
+                    Component::new(2.0, vec![p2]),
+                    Component::new(-3.0, vec![p1, p3]),
+                    Component::new(4.0, vec![p1, p2, p3]),
+                ]);
+                let sum = &lhs + &rhs;
+                assert_eq!(bases(&sum), vec![vec![], vec![p1], vec![p2], vec![p1, p2, p3]]);
+                assert_eq!(sum.components()[2].mag, 4.0);
+                let diff = &lhs - &rhs;
+                assert_eq!(bases(&diff), vec![vec![], vec![p1], vec![p1, p3], vec![p1, p2, p3]]);
+                assert_eq!(diff.components()[1].mag, -1.0);
+                assert_eq!(diff.components()[2].mag, 6.0);
+                assert_eq!(&lhs - &lhs, Multivector::ZERO);
+                let added = lhs.component_add(&Component::new(1.0, vec![p3]));
+                assert_eq!(bases(&added), vec![vec![], vec![p2], vec![p3], vec![p1, p3]]);
+            }
+
+            #[test]
+            pub fn keep_products_in_order() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let lhs = Multivector::new(vec![
+                    Component::new(1.0, vec![p3]),
+                    Component::new(1.0, vec![p1]),
+                ]);
+                let rhs = Multivector::new(vec![
+                    Component::new(1.0, vec![p2]),
+                    Component::new(1.0, vec![p1]),
+                ]);
+                let prod = &lhs * &rhs;
+                assert_eq!(bases(&prod), vec![vec![], vec![p1, p2], vec![p1, p3], vec![p2, p3]]);
+                let outer = &lhs ^ &rhs;
+                assert_eq!(bases(&outer), vec![vec![p1, p2], vec![p1, p3], vec![p2, p3]]);
+            }
+        }
+
+        mod add_component_should {
+            use crate::{basis::ONBasis, component::{Component, self}, multivector::{self, Multivector}};
+
+            #[test]
+            pub fn correctly_add_component_to_multivector() {
+                let b1 = ONBasis::P(1);
+                let b2 = ONBasis::P(2);
+
+                let comp1 = Component::new(1.0, 
+                    vec![b1]);
+                let comp2 = Component::new(1.0, 
+                    vec![b2]);
+                let comp12 = Component::new(1.0, 
+                    vec![b1, b2]);
+
+                let mv = multivector::ZERO;
+
+                let mv1 = mv.component_add(&comp1);
+                assert_eq!(mv1.len(), 1);
+                assert_eq!(mv1.components()[0].mag, 1.0);
+                assert_eq!(mv1.components()[0].bases(), vec![b1]);
+
+                let mv1p = mv1.component_add(&comp1);
+                assert_eq!(mv1p.len(), 1);
+                assert_eq!(mv1p.components()[0].mag, 2.0);
+                assert_eq!(mv1p.components()[0].bases(), vec![b1]);
 
                 let mv12 = mv1p.component_add(&comp2);
                 assert_eq!(mv12.len(), 2);
@@ -1547,6 +2559,270 @@ mod tests {
             }
         }
 
+        mod contraction_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+            #[test]
+            pub fn follow_grade_rules() {
+                let (p1, p2, p3) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3));
+                let s = Component::new(2.0, vec![]);
+                let c1 = Component::new(1.0, vec![p1]);
+                let c2 = Component::new(1.0, vec![p2]);
+                let c12 = Component::new(1.0, vec![p1, p2]);
+                let c23 = Component::new(1.0, vec![p2, p3]);
+
+                // (2 + e1 + e2) << (e12 + e23) = 2e12 + 2e23 + e2 - e1 + e3
+                let lhs = Multivector::new(vec![s.clone(), c1.clone(), c2.clone()]);
+                let rhs = Multivector::new(vec![c12.clone(), c23.clone()]);
+                let result = &lhs << &rhs;
+                assert_eq!(result, Multivector::new(vec![
+                    Component::new(2.0, vec![p1, p2]),
+                    Component::new(2.0, vec![p2, p3]),
+                    Component::new(1.0, vec![p2]),
+                    Component::new(-1.0, vec![p1]),
+                    Component::new(1.0, vec![p3]),
+                ]));
+
+                // the higher grade on the left drops out.
+                assert_eq!(&rhs << &lhs, Multivector::ZERO);
+                // and the reverse for right contraction.
+                let result = &rhs >> &lhs;
+                assert_eq!(result, Multivector::new(vec![
+                    Component::new(2.0, vec![p1, p2]),
+                    Component::new(2.0, vec![p2, p3]),
+                    Component::new(1.0, vec![p1]),
+                    Component::new(-1.0, vec![p2]),
+                    Component::new(-1.0, vec![p3]),
+                ]));
+                assert_eq!(&lhs >> &c12, Multivector::ZERO);
+            }
+
+            #[test]
+            pub fn match_component_contractions() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let c1 = Component::new(3.0, vec![p1]);
+                let c12 = Component::new(2.0, vec![p1, p2]);
+
+                assert_eq!(c1.to_mv() << c12.to_mv(), (&c1 << &c12).to_mv());
+                assert_eq!(c12.to_mv() >> c1.to_mv(), (&c12 >> &c1).to_mv());
+                assert_eq!(&c1 << c12.to_mv(), c1.to_mv() << &c12);
+                assert_eq!(&c12 >> c1.to_mv(), c12.to_mv() >> &c1);
+            }
+        }
+
+        mod reverse_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+            #[test]
+            pub fn apply_grade_signs() {
+                let (p1, p2, p3, p4) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4));
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![p1]),
+                    Component::new(1.0, vec![p1, p2]),
+                    Component::new(1.0, vec![p1, p2, p3]),
+                    Component::new(1.0, vec![p1, p2, p3, p4]),
+                ]);
+                let signs = |mv: &Multivector| mv.components().iter()
+                    .map(|x| x.mag).collect::<Vec<_>>();
+
+                assert_eq!(signs(&mv.reverse()), vec![1.0, 1.0, -1.0, -1.0, 1.0]);
+                assert_eq!(signs(&mv.grade_involution()), vec![1.0, -1.0, 1.0, -1.0, 1.0]);
+                assert_eq!(signs(&mv.clifford_conjugate()), vec![1.0, -1.0, -1.0, 1.0, 1.0]);
+                assert_eq!(!&mv, mv.reverse());
+                assert_eq!(!mv.reverse(), mv);
+            }
+
+            #[test]
+            pub fn make_sandwiches_read_naturally() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                // 90 degree rotor in the p1p2 plane
+                let half = std::f64::consts::FRAC_1_SQRT_2;
+                let r = Multivector::new(vec![
+                    Component::new(half, vec![]),
+                    Component::new(-half, vec![p1, p2]),
+                ]);
+                let x = ONBasis::P(1).to_mv();
+                let result = &r * &x * !&r;
+                assert!((result.components()[0].mag - 1.0).abs() < 1e-12);
+                assert_eq!(result.components()[0].bases(), vec![p2]);
+            }
+        }
+
+        mod inverse_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+            #[test]
+            pub fn invert_mixed_grades() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![p1, p2]),
+                ]);
+                let expected = Multivector::new(vec![
+                    Component::new(0.5, vec![]),
+                    Component::new(-0.5, vec![p1, p2]),
+                ]);
+                let result = mv.inverse().unwrap();
+                assert!(result.approx_eq(&expected, 1e-12));
+                assert!((&mv * &result).approx_eq(&Component::new(1.0, vec![]).to_mv(), 1e-12));
+                assert!((&result * &mv).approx_eq(&Component::new(1.0, vec![]).to_mv(), 1e-12));
+            }
+
+            #[test]
+            pub fn invert_negative_and_degenerate() {
+                let (n1, z0) = (ONBasis::N(1), ONBasis::Z(0));
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![n1]),
+                ]);
+                let expected = Multivector::new(vec![
+                    Component::new(0.5, vec![]),
+                    Component::new(-0.5, vec![n1]),
+                ]);
+                assert!(mv.inverse().unwrap().approx_eq(&expected, 1e-12));
+
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![z0]),
+                ]);
+                let expected = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(-1.0, vec![z0]),
+                ]);
+                assert!(mv.inverse().unwrap().approx_eq(&expected, 1e-12));
+            }
+
+            #[test]
+            pub fn return_none_when_not_invertable() {
+                let (p1, z0) = (ONBasis::P(1), ONBasis::Z(0));
+                assert_eq!(Multivector::ZERO.inverse(), None);
+                assert_eq!(z0.to_mv().inverse(), None);
+                // (1 + p1)(1 - p1) = 0, so no inverse.
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![]),
+                    Component::new(1.0, vec![p1]),
+                ]);
+                assert_eq!(mv.inverse(), None);
+                assert_eq!(mv.norm_sqrd(), None);
+                assert_eq!(z0.to_mv().norm_sqrd(), None);
+                assert_eq!((p1.to_mv() + ONBasis::N(1).to_mv()).norm_sqrd(), None);
+                assert_eq!(p1.to_mv().div_mv(&mv), None);
+            }
+
+            #[test]
+            pub fn invert_general_elements() {
+                let (p1, p2, p3, n1) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::N(1));
+                let mv = Multivector::new(vec![
+                    Component::new(2.0, vec![]),
+                    Component::new(0.5, vec![p1]),
+                    Component::new(-1.5, vec![p2, p3]),
+                    Component::new(0.25, vec![n1, p1, p3]),
+                ]);
+                let one = Component::new(1.0, vec![]).to_mv();
+                let inv = mv.inverse().unwrap();
+                assert!((&mv * &inv).approx_eq(&one, 1e-12));
+                assert!((&inv * &mv).approx_eq(&one, 1e-12));
+                assert!(mv.div_mv(&mv).unwrap().approx_eq(&one, 1e-12));
+            }
+        }
+
+        mod exp_should {
+            use crate::{basis::ONBasis, tests::common::mv};
+
+            #[test]
+            pub fn use_closed_forms_for_simple_planes() {
+                let (p1, p2, n1, z0) = (ONBasis::P(1), ONBasis::P(2), ONBasis::N(1), ONBasis::Z(0));
+                let theta: f64 = 0.7;
+                // p1p2 squares to -1
+                let rotor = mv(vec![(theta, vec![p1, p2])]).exp();
+                assert!(rotor.approx_eq(&mv(vec![(theta.cos(), vec![]), (theta.sin(), vec![p1, p2])]), 1e-9));
+                // p1n1 squares to +1
+                let boost = mv(vec![(theta, vec![p1, n1])]).exp();
+                assert!(boost.approx_eq(&mv(vec![(theta.cosh(), vec![]), (theta.sinh(), vec![p1, n1])]), 1e-9));
+                // z0p1 squares to 0
+                let translator = mv(vec![(theta, vec![z0, p1])]).exp();
+                assert!(translator.approx_eq(&mv(vec![(1.0, vec![]), (theta, vec![z0, p1])]), 1e-9));
+                // a scalar part scales it.
+                let scaled = mv(vec![(2.0, vec![]), (theta, vec![p1, p2])]).exp();
+                assert!(scaled.approx_eq(&(rotor * 2.0_f64.exp()), 1e-9));
+            }
+
+            #[test]
+            pub fn rotate_vectors_in_a_sandwich() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let angle = std::f64::consts::FRAC_PI_2;
+                let rotor = mv(vec![(-angle / 2.0, vec![p1, p2])]).exp();
+                let result = &rotor * p1.to_mv() * rotor.reverse();
+                assert!(result.approx_eq(&p2.to_mv(), 1e-9));
+            }
+
+            #[test]
+            pub fn fall_back_to_the_series() {
+                let (p1, p2, p3, p4) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4));
+                // not simple, but the two planes commute.
+                let b = mv(vec![(0.3, vec![p1, p2]), (2.5, vec![p3, p4])]);
+                let expected = mv(vec![(0.3, vec![p1, p2])]).exp() * mv(vec![(2.5, vec![p3, p4])]).exp();
+                assert!(b.exp().approx_eq(&expected, 1e-9));
+                // mixed grade, exp(a) exp(-a) = 1
+                let a = mv(vec![(1.5, vec![p1]), (0.5, vec![p1, p2]), (3.0, vec![p2, p3, p4])]);
+                assert!((a.exp() * (-&a).exp()).approx_eq(&mv(vec![(1.0, vec![])]), 1e-9));
+            }
+        }
+
+        mod log_should {
+            use crate::{basis::ONBasis, multivector::Multivector, tests::common::mv};
+
+            #[test]
+            pub fn undo_exp() {
+                let (p1, p2, p3, p4, n1, z0) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4), ONBasis::N(1), ONBasis::Z(0));
+                for b in [
+                    mv(vec![(0.7, vec![p1, p2])]),
+                    mv(vec![(-2.5, vec![p1, p2]), (1.0, vec![p2, p3])]),
+                    mv(vec![(0.7, vec![p1, n1])]),
+                    mv(vec![(0.7, vec![z0, p1]), (-0.2, vec![z0, p2])]),
+                    mv(vec![(0.5, vec![]), (0.7, vec![p1, p2])]),
+                    mv(vec![(0.3, vec![p1, p2]), (0.5, vec![p3, p4])]),
+                ] {
+                    let log = b.exp().log().unwrap();
+                    assert!(log.approx_eq(&b, 1e-9), "{} became {}", b.to_string(), log.to_string());
+                }
+            }
+
+            #[test]
+            pub fn return_none_without_a_real_log() {
+                let (p1, n1) = (ONBasis::P(1), ONBasis::N(1));
+                assert_eq!(mv(vec![(-1.0, vec![])]).log(), None);
+                assert_eq!(Multivector::ZERO.log(), None);
+                assert_eq!(mv(vec![(-2.0, vec![]), (1.0, vec![p1, n1])]).log(), None);
+            }
+        }
+
+        mod sqrt_should {
+            use crate::{basis::ONBasis, tests::common::mv};
+
+            #[test]
+            pub fn halve_rotors() {
+                let (p1, p2, p3, p4, n1, z0) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4), ONBasis::N(1), ONBasis::Z(0));
+                for b in [
+                    mv(vec![(1.2, vec![p1, p2])]),
+                    mv(vec![(0.7, vec![p1, n1])]),
+                    mv(vec![(0.7, vec![z0, p1])]),
+                    mv(vec![(0.3, vec![p1, p2]), (1.1, vec![p3, p4])]),
+                ] {
+                    let rotor = b.exp() * 3.0;
+                    let root = rotor.sqrt().unwrap();
+                    assert!((&root * &root).approx_eq(&rotor, 1e-9), "sqrt of {}", rotor.to_string());
+                    assert!(root.approx_eq(&((&b * 0.5).exp() * 3.0_f64.sqrt()), 1e-9));
+                }
+            }
+
+            #[test]
+            pub fn return_none_for_negative_one() {
+                assert_eq!(mv(vec![(-1.0, vec![])]).sqrt(), None);
+            }
+        }
+
         mod negative_should {
             use crate::{basis::ONBasis, component::Component, multivector::Multivector};
 
@@ -1579,5 +2855,780 @@ mod tests {
                 assert_eq!(test.components()[3].bases(), vec![b1, b2]);
             }
         }
+
+        mod clean_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector};
+
+            #[test]
+            pub fn remove_tiny_components() {
+                let mv = Multivector::new(vec![
+                    Component::new(1.0, vec![ONBasis::P(1)]),
+                    Component::new(1e-17, vec![ONBasis::P(2)]),
+                    Component::new(-1e-17, vec![ONBasis::P(1), ONBasis::P(2)]),
+                ]);
+                assert_eq!(mv.len(), 3);
+                let cleaned = mv.clean(1e-12);
+                assert_eq!(cleaned, Multivector::new(vec![Component::new(1.0, vec![ONBasis::P(1)])]));
+                assert_eq!(cleaned.blades.len(), 1);
+                assert_eq!(mv.clean(0.0), mv);
+            }
+
+            #[test]
+            pub fn compare_approximately() {
+                let p1 = Component::new(1.0, vec![ONBasis::P(1)]);
+                let mv = Multivector::new(vec![p1.clone(), Component::new(1e-17, vec![ONBasis::P(2)])]);
+                assert_ne!(mv, p1.to_mv());
+                assert!(mv.approx_eq(&p1.to_mv(), 1e-12));
+                assert!(p1.to_mv().approx_eq(&mv, 1e-12));
+                assert!(!mv.approx_eq(&(&p1 * 2.0).to_mv(), 1e-12));
+                assert!(Multivector::ZERO.approx_eq(&Multivector::new(vec![Component::new(1e-17, vec![])]), 1e-12));
+            }
+        }
+    }
+    mod pga_tests {
+        mod pga_should {
+            use crate::{multivector::Multivector, pga::Pga, tests::common::close_coords};
+
+            #[test]
+            pub fn build_points_and_read_them_back() {
+                let pga = Pga::new(3).unwrap();
+                let point = pga.point(&[1.0, -2.0, 3.5]).unwrap();
+                assert_eq!(point.grades(), vec![3]);
+                assert!(close_coords(&pga.coords(&point).unwrap(), &[1.0, -2.0, 3.5]));
+                assert!(close_coords(&pga.coords(&(&point * 4.0)).unwrap(), &[1.0, -2.0, 3.5]));
+                assert_eq!(pga.weight_norm(&point), 1.0);
+                assert_eq!(pga.weight_norm(&pga.direction(&[1.0, 0.0, 0.0]).unwrap()), 0.0);
+                assert_eq!(pga.coords(&pga.direction(&[1.0, 0.0, 0.0]).unwrap()), None);
+                assert!(pga.point(&[1.0, 2.0]).is_err());
+                assert!(Pga::new(0).is_err());
+
+                let flat = Pga::new(2).unwrap();
+                let point = flat.point(&[3.0, 4.0]).unwrap();
+                assert_eq!(point.grades(), vec![2]);
+                assert!(close_coords(&flat.coords(&point).unwrap(), &[3.0, 4.0]));
+                assert!((flat.bulk_norm(&point) - 5.0).abs() < 1e-12);
+            }
+
+            #[test]
+            pub fn join_and_meet() {
+                let pga = Pga::new(3).unwrap();
+                let (a, b) = (pga.point(&[0.0, 0.0, 0.0]).unwrap(), pga.point(&[3.0, 4.0, 0.0]).unwrap());
+                let line = pga.join(&a, &b);
+                assert_eq!(line.grades(), vec![2]);
+                assert_eq!(line, pga.inverse_complement(&(pga.complement(&a) ^ pga.complement(&b))));
+                assert_eq!(pga.inverse_complement(&pga.complement(&line)), line);
+                assert_eq!(pga.distance(&a, &b), Some(5.0));
+                // the line lies in the plane z = 0, and meets the plane x = 3 at b.
+                let floor = pga.plane(&[0.0, 0.0, 1.0], 0.0).unwrap();
+                assert!(pga.meet(&line, &floor).approx_eq(&Multivector::ZERO, 1e-9));
+                let wall = pga.plane(&[1.0, 0.0, 0.0], -3.0).unwrap();
+                let hit = pga.meet(&line, &wall);
+                assert!(close_coords(&pga.coords(&hit).unwrap(), &[3.0, 4.0, 0.0]));
+                // three points join into their plane.
+                let c = pga.point(&[0.0, 0.0, 2.0]).unwrap();
+                let plane = pga.join(&line, &c);
+                assert_eq!(plane.grades(), vec![1]);
+                assert!(pga.meet(&plane, &pga.point(&[6.0, 8.0, -1.0]).unwrap()).approx_eq(&Multivector::ZERO, 1e-9));
+
+                let flat = Pga::new(2).unwrap();
+                let line = flat.line(&[0.0, 1.0], &[1.0, 1.0]).unwrap();
+                let other = flat.line(&[2.0, 0.0], &[2.0, 5.0]).unwrap();
+                assert!(close_coords(&flat.coords(&flat.meet(&line, &other)).unwrap(), &[2.0, 1.0]));
+            }
+
+            #[test]
+            pub fn move_points_with_motors() {
+                let pga = Pga::new(3).unwrap();
+                let point = pga.point(&[1.0, 0.0, 0.0]).unwrap();
+                let translator = pga.translator(&[1.0, 2.0, 3.0]).unwrap();
+                assert!(close_coords(&pga.coords(&translator.apply(&point)).unwrap(), &[2.0, 2.0, 3.0]));
+                // rotate a quarter turn around the z axis.
+                let axis = pga.line(&[0.0, 0.0, 0.0], &[0.0, 0.0, 1.0]).unwrap();
+                let rotor = pga.rotor(&axis, std::f64::consts::FRAC_PI_2).unwrap();
+                assert!(close_coords(&pga.coords(&rotor.apply(&point)).unwrap(), &[0.0, 1.0, 0.0]));
+                let motor = pga.motor(&rotor, &translator);
+                assert!(close_coords(&pga.coords(&motor.apply(&point)).unwrap(), &[1.0, 3.0, 3.0]));
+                assert!(pga.rotor(&pga.e0(), 1.0).is_err());
+
+                let flat = Pga::new(2).unwrap();
+                let center = flat.point(&[1.0, 1.0]).unwrap();
+                let rotor = flat.rotor(&center, std::f64::consts::PI).unwrap();
+                let moved = rotor.apply(&flat.point(&[2.0, 1.0]).unwrap());
+                assert!(close_coords(&flat.coords(&moved).unwrap(), &[0.0, 1.0]));
+                let rotor = flat.rotor(&center, std::f64::consts::FRAC_PI_2).unwrap();
+                let moved = rotor.apply(&flat.point(&[2.0, 1.0]).unwrap());
+                assert!(close_coords(&flat.coords(&moved).unwrap(), &[1.0, 2.0]));
+            }
+        }
+    }
+
+    mod rational_tests {
+        mod rational_should {
+            use crate::{bigint::BigInt, rational::Rational, scalar::Scalar};
+
+            fn rat(num: i64, den: i64) -> Rational {
+                Rational::from_ints(num, den).unwrap()
+            }
+
+            #[test]
+            pub fn keep_lowest_terms() {
+                assert_eq!(rat(2, 4), rat(1, 2));
+                assert_eq!(rat(3, -6), rat(-1, 2));
+                assert_eq!(rat(-3, -6).denominator(), &BigInt::from(2i64));
+                assert_eq!(rat(0, -5), Rational::zero());
+                assert_eq!(Rational::from_ints(1, 0), None);
+                assert_eq!(rat(-1, 3).to_string(), "-1/3");
+                assert_eq!(rat(6, 3).to_string(), "2");
+            }
+
+            #[test]
+            pub fn do_exact_arithmetic() {
+                assert_eq!(rat(1, 3) + rat(1, 6), rat(1, 2));
+                assert_eq!(rat(1, 3) - rat(1, 2), rat(-1, 6));
+                assert_eq!(rat(2, 3) * rat(9, 4), rat(3, 2));
+                assert_eq!(rat(2, 3) / rat(-4, 9), rat(-3, 2));
+                assert_eq!(-rat(1, 3), rat(-1, 3));
+                assert_eq!(rat(1, 3) * rat(3, 1), Rational::one());
+                assert_eq!(Rational::zero().recip(), None);
+                assert!(rat(1, 3) < rat(1, 2) && rat(-1, 2) < rat(-1, 3));
+            }
+
+            #[test]
+            pub fn parse_numbers_and_fractions() {
+                assert_eq!("1/3".parse::<Rational>().unwrap(), rat(1, 3));
+                assert_eq!("-0.25".parse::<Rational>().unwrap(), rat(-1, 4));
+                assert_eq!(".5".parse::<Rational>().unwrap(), rat(1, 2));
+                assert_eq!("1.5/2".parse::<Rational>().unwrap(), rat(3, 4));
+                assert_eq!("+7".parse::<Rational>().unwrap(), rat(7, 1));
+                assert!("1/0".parse::<Rational>().is_err());
+                assert!(".".parse::<Rational>().is_err());
+                assert!("1.2.3".parse::<Rational>().is_err());
+            }
+
+            #[test]
+            pub fn convert_floats_exactly() {
+                assert_eq!(Rational::from_f64(0.375), rat(3, 8));
+                assert_eq!(Rational::from_f64(-3.0), rat(-3, 1));
+                // 0.1 isn't exact as a float, so we get it's true value.
+                assert_ne!(Rational::from_f64(0.1), rat(1, 10));
+                assert_eq!(Rational::from_f64(0.1).to_f64(), 0.1);
+                assert_eq!(Rational::from_f64(f64::NAN), Rational::zero());
+                assert_eq!(rat(1, 3).to_f64(), 1.0 / 3.0);
+                assert_eq!(rat(-2, 3).magnitude(), 2.0 / 3.0);
+                let huge = Rational::from(BigInt::from(10i64).pow(400));
+                assert_eq!((&huge / &(&huge * &rat(3, 1))).to_f64(), 1.0 / 3.0);
+                // 2^53 + 1 + 2^-100 is just past the tie between 2^53 and 2^53 + 2.
+                let den = BigInt::from(2i64).pow(100);
+                let num = &(&BigInt::from((1i64 << 53) + 1) * &den) + &BigInt::one();
+                let past_tie = Rational::new(num, den).unwrap();
+                assert_eq!(past_tie.to_f64(), 2f64.powi(53) + 2.0);
+                assert_eq!((-past_tie).to_f64(), -(2f64.powi(53) + 2.0));
+            }
+        }
+
+        mod exact_algebra_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector, rational::Rational, scalar::Scalar};
+
+            fn rat(num: i64, den: i64) -> Rational {
+                Rational::from_ints(num, den).unwrap()
+            }
+
+            #[test]
+            pub fn parse_fraction_literals() {
+                let mv: Multivector<Rational> = "1/3P(1)+1/6P(2)-1/3P(1)".parse().unwrap();
+                assert_eq!(mv, Component::new(rat(1, 6), vec![ONBasis::P(2)]).to_mv());
+                let comp: Component<Rational> = "-0.1P(1)N(1)".parse().unwrap();
+                assert_eq!(comp, Component::new(rat(-1, 10), vec![ONBasis::P(1), ONBasis::N(1)]));
+                assert!("1/0P(1)".parse::<Component<Rational>>().is_err());
+            }
+
+            #[test]
+            pub fn invert_with_tiny_pivots() {
+                // (1 + aP(1))^-1 is (1 - aP(1)) / (1 - a^2), and 1 - a^2 is about 2e-14 here.
+                let a = rat(99_999_999_999_999, 100_000_000_000_000);
+                let mv = Multivector::new(vec![
+                    Component::new(Rational::one(), vec![]),
+                    Component::new(a.clone(), vec![ONBasis::P(1)]),
+                ]);
+                let inv = mv.inverse().unwrap();
+                assert_eq!(&mv * &inv, Component::new(Rational::one(), vec![]).to_mv());
+                let det = Rational::one() - a.clone() * a.clone();
+                assert_eq!(inv.components()[0].mag, Rational::one() / det);
+                // but a = 1 is still not invertible.
+                let mv = Multivector::new(vec![
+                    Component::new(Rational::one(), vec![]),
+                    Component::new(Rational::one(), vec![ONBasis::P(1)]),
+                ]);
+                assert_eq!(mv.inverse(), None);
+            }
+
+            #[test]
+            pub fn check_identities_with_exact_equality() {
+                let e1: Multivector<Rational> = "1/3P(1)+2/7P(2)".parse().unwrap();
+                let e2: Multivector<Rational> = "3/5P(2)-1/9N(1)".parse().unwrap();
+                let a = &e1 * &e2 + rat(1, 11);
+                // a a^-1 is exactly 1, without any floating point noise.
+                let inv = a.inverse().unwrap();
+                assert_eq!(&a * &inv, Component::new(Rational::one(), vec![]).to_mv());
+                assert_eq!(&inv * &a, Component::new(Rational::one(), vec![]).to_mv());
+
+                // the dual of the dual is A I^-2, so A when I^2 = 1 and -A when I^2 = -1.
+                let i = Component::new(Rational::one(), vec![ONBasis::P(1), ONBasis::P(2), ONBasis::N(1)]);
+                let blade = Component::new(rat(5, 7), vec![ONBasis::P(1), ONBasis::N(1)]);
+                assert_eq!(blade.dual(&i).unwrap().dual(&i).unwrap(), blade);
+                let i = Component::new(Rational::one(), vec![ONBasis::P(1), ONBasis::P(2), ONBasis::P(3)]);
+                let euclid = Component::new(rat(5, 7), vec![ONBasis::P(1), ONBasis::P(3)]);
+                assert_eq!(euclid.dual(&i).unwrap().dual(&i).unwrap(), -euclid.clone());
+                let i = Component::new(rat(2, 3), vec![ONBasis::P(1), ONBasis::P(2), ONBasis::N(1)]);
+                assert_eq!(blade.dual(&i).unwrap().undual(&i).unwrap(), blade);
+
+                // reciprocal frames give e^i . e_j = δ_ij exactly.
+                let frame = Component::new(Rational::one(), vec![ONBasis::P(1), ONBasis::N(2), ONBasis::P(3)]);
+                for idx in 0..3 {
+                    let recip = frame.reciprocal_frame(idx);
+                    for (jdx, basis) in frame.bases().iter().enumerate() {
+                        let expected = if idx == jdx { Rational::one() } else { Rational::zero() };
+                        assert_eq!(recip.scalar_product(&Component::new(Rational::one(), vec![*basis])), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    mod scalar_tests {
+        mod generic_should {
+            use std::{fmt, ops};
+            use crate::{basis::ONBasis, blade::Blade, component::Component, multivector::Multivector, scalar::Scalar, vector::Vector};
+
+            /// A bare complex number, only here to show non-float scalars work.
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            struct Complex(f64, f64);
+
+            impl fmt::Display for Complex {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "({}+{}i)", self.0, self.1)
+                }
+            }
+
+            impl ops::Add for Complex {
+                type Output = Complex;
+                fn add(self, rhs: Complex) -> Complex { Complex(self.0 + rhs.0, self.1 + rhs.1) }
+            }
+
+            impl ops::Sub for Complex {
+                type Output = Complex;
+                fn sub(self, rhs: Complex) -> Complex { Complex(self.0 - rhs.0, self.1 - rhs.1) }
+            }
+
+            impl ops::Mul for Complex {
+                type Output = Complex;
+                fn mul(self, rhs: Complex) -> Complex {
+                    Complex(self.0 * rhs.0 - self.1 * rhs.1, self.0 * rhs.1 + self.1 * rhs.0)
+                }
+            }
+
+            impl ops::Div for Complex {
+                type Output = Complex;
+                fn div(self, rhs: Complex) -> Complex {
+                    let den = rhs.0 * rhs.0 + rhs.1 * rhs.1;
+                    Complex((self.0 * rhs.0 + self.1 * rhs.1) / den, (self.1 * rhs.0 - self.0 * rhs.1) / den)
+                }
+            }
+
+            impl ops::Neg for Complex {
+                type Output = Complex;
+                fn neg(self) -> Complex { Complex(-self.0, -self.1) }
+            }
+
+            impl Scalar for Complex {
+                fn zero() -> Self { Complex(0.0, 0.0) }
+                fn one() -> Self { Complex(1.0, 0.0) }
+                fn from_f64(val: f64) -> Self { Complex(val, 0.0) }
+                fn magnitude(&self) -> f64 { self.0.hypot(self.1) }
+            }
+
+            #[test]
+            pub fn multiply_with_f32() {
+                let a = Component::new(2.0f32, vec![ONBasis::P(1)]);
+                let b = Component::new(3.0f32, vec![ONBasis::P(2)]);
+                assert_eq!(&a * &b, Component::new(6.0f32, vec![ONBasis::P(1), ONBasis::P(2)]));
+                assert_eq!(&b * &a, Component::new(-6.0f32, vec![ONBasis::P(1), ONBasis::P(2)]));
+                assert_eq!(Component::new(2.0f32, vec![ONBasis::N(1)]).norm_sqrd(), -4.0f32);
+
+                let mv = Multivector::new(vec![Component::new(1.0f32, vec![]), a.clone()]);
+                assert_eq!(&mv * 2.0f32, Multivector::new(vec![
+                    Component::new(2.0f32, vec![]), Component::new(4.0f32, vec![ONBasis::P(1)])]));
+                assert_eq!(&mv - &mv, Multivector::<f32>::zero());
+                let inv = mv.inverse().unwrap();
+                assert_eq!(&mv * &inv, Component::new(1.0f32, vec![]).to_mv());
+            }
+
+            #[test]
+            pub fn work_with_complex_numbers() {
+                let i = Complex(0.0, 1.0);
+                let a = Component::new(i, vec![ONBasis::P(1)]);
+                // (i e1)^2 = -1
+                assert_eq!(&a * &a, Component::new(Complex(-1.0, 0.0), vec![]));
+                assert_eq!(a.reversion(), a);
+
+                let mv = Multivector::new(vec![Component::new(Complex::one(), vec![]), a.clone()]);
+                let inv = mv.inverse().unwrap();
+                assert_eq!(&mv * &inv, Component::new(Complex::one(), vec![]).to_mv());
+                assert_eq!(&mv + Complex(2.0, 0.0), Multivector::new(vec![
+                    Component::new(Complex(3.0, 0.0), vec![]), a.clone()]));
+
+                let e1 = Vector::from_component(&Component::new(Complex::one(), vec![ONBasis::P(1)])).unwrap();
+                let e2 = Vector::from_component(&Component::new(i, vec![ONBasis::P(2)])).unwrap();
+                let blade = Blade::new(&vec![e1, e2]);
+                assert_eq!(blade.grade(), 2);
+                assert_eq!(blade.to_mv(), Component::new(i, vec![ONBasis::P(1), ONBasis::P(2)]).to_mv());
+            }
+
+            #[test]
+            pub fn keep_f64_as_the_default() {
+                let comp: Component = Component::new(1.5, vec![ONBasis::P(1)]);
+                let mv: Multivector = 2.0 * comp.to_mv();
+                assert_eq!(mv, Component::new(3.0, vec![ONBasis::P(1)]).to_mv());
+                assert_eq!(Multivector::ZERO, Multivector::<f64>::zero());
+                assert_eq!(Component::ZERO, Component::<f64>::zero());
+            }
+        }
+    }
+
+    mod symbolic_tests {
+        mod symbolic_should {
+            use crate::{rational::Rational, scalar::Scalar, symbolic::Symbolic};
+
+            #[test]
+            pub fn expand_and_collect_terms() {
+                let a = Symbolic::var("a");
+                let b = Symbolic::var("b");
+                let sqrs = (&a + &b) * (&a - &b);
+                assert_eq!(sqrs.to_string(), "(a^2 - b^2)");
+                assert_eq!((&a * &b * Symbolic::from(3) - &b * &a).to_string(), "2·a·b");
+                assert_eq!((&a - &a).to_string(), "0");
+                assert_eq!(a.pow(3).unwrap().to_string(), "a^3");
+                assert_eq!((&a + Symbolic::from(1)).pow(2).unwrap().to_string(), "(a^2 + 2·a + 1)");
+                assert_eq!(sqrs.variables(), vec!["a", "b"]);
+                assert_eq!(Symbolic::from(2).as_constant(), Some(Rational::from(2)));
+                assert_eq!(a.as_constant(), None);
+            }
+
+            #[test]
+            pub fn simplify_fractions() {
+                let a = Symbolic::var("a");
+                let b = Symbolic::var("b");
+                let sqrs = (&a + &b) * (&a - &b);
+                assert_eq!((&sqrs / (&a - &b)).to_string(), "(a + b)");
+                assert_eq!(((&a - &b) / &sqrs).to_string(), "1/(a + b)");
+                assert_eq!((&a * &a * &b / (&a * Symbolic::from(2))).to_string(), "1/2·a·b");
+                assert_eq!(a.pow(-2).unwrap().to_string(), "1/a^2");
+                assert_eq!(Symbolic::zero().pow(-1), None);
+                // equal values are equal, however they were reached.
+                assert_eq!(&a / &b + Symbolic::one(), (&a + &b) / &b);
+                assert_ne!(&a / &b, &b / &a);
+                assert_eq!(&a / &a, Symbolic::one());
+            }
+        }
+
+        mod algebra_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector, scalar::Scalar, symbolic::Symbolic};
+
+            fn vector(prefix: &str) -> Multivector<Symbolic> {
+                Multivector::new((1..=3)
+                    .map(|idx| Component::new(Symbolic::var(&format!("{}{}", prefix, idx)), vec![ONBasis::P(idx)]))
+                    .collect())
+            }
+
+            #[test]
+            pub fn give_closed_form_products() {
+                let a = Symbolic::var("a");
+                let b = Symbolic::var("b");
+                let c = Symbolic::var("c");
+                let lhs = Multivector::new(vec![
+                    Component::new(a.clone(), vec![ONBasis::P(1)]),
+                    Component::new(b.clone(), vec![ONBasis::P(2)]),
+                ]);
+                let rhs = Component::new(c.clone(), vec![ONBasis::P(1)]).to_mv();
+                // b c P(2)P(1) is -b c P(1)P(2) in canonical order.
+                assert_eq!(&lhs * &rhs, Multivector::new(vec![
+                    Component::new(&a * &c, vec![]),
+                    Component::new(-(&b * &c), vec![ONBasis::P(1), ONBasis::P(2)]),
+                ]));
+                assert_eq!((&lhs * &rhs).components()[0].mag.to_string(), "a·c");
+
+                // |x|^2 of a vector is the usual sum of squares.
+                let x = vector("x");
+                assert_eq!(x.norm_sqrd().unwrap().to_string(), "(x1^2 + x2^2 + x3^2)");
+            }
+
+            #[test]
+            pub fn check_left_contraction_properties() {
+                let (a, b, c) = (vector("a"), vector("b"), vector("c"));
+                // a>>(b ^ c) = (a>>b) c - (a>>c) b
+                assert_eq!(&a << (&b ^ &c), (&a << &b) * &c - (&a << &c) * &b);
+                // (a ^ b)>>C = a>>(b>>C)
+                let big_c = (&a ^ &b ^ &c) + (&b ^ &c) + &c;
+                assert_eq!(big_c.grades(), vec![1, 2, 3]);
+                assert_eq!((&a ^ &b) << &big_c, &a << (&b << &big_c));
+                // the result of a>>B is perpendicular to a.
+                assert_eq!(&a << (&a << (&b ^ &c)), Multivector::zero());
+            }
+
+            #[test]
+            pub fn invert_symbolically() {
+                let mv = Multivector::new(vec![
+                    Component::new(Symbolic::var("s"), vec![]),
+                    Component::new(Symbolic::var("t"), vec![ONBasis::P(1), ONBasis::P(2)]),
+                ]);
+                let inv = mv.inverse().unwrap();
+                assert_eq!(&mv * &inv, Component::new(Symbolic::one(), vec![]).to_mv());
+                assert_eq!(inv.components()[0].mag.to_string(), "s/(s^2 + t^2)");
+            }
+        }
+    }
+
+    mod versor_tests {
+        mod versor_should {
+            use crate::{basis::ONBasis, component::Component, multivector::Multivector, vector::Vector, versor::Versor, tests::common::mv};
+
+            fn vector(parts: Vec<(f64, ONBasis)>) -> Vector {
+                Vector::new(&parts.into_iter().map(|(mag, basis)| Component::new(mag, vec![basis])).collect())
+            }
+
+            #[test]
+            pub fn reflect_with_the_right_sign() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let reflection = Versor::from_vectors(&[vector(vec![(2.0, p1)])]).unwrap();
+                assert!(reflection.is_odd());
+                let x = mv(vec![(1.0, vec![p1]), (1.0, vec![p2])]);
+                assert!(reflection.apply(&x).approx_eq(&mv(vec![(-1.0, vec![p1]), (1.0, vec![p2])]), 1e-9));
+                let plane = mv(vec![(1.0, vec![p1, p2])]);
+                assert!(reflection.apply(&plane).approx_eq(&mv(vec![(-1.0, vec![p1, p2])]), 1e-9));
+            }
+
+            #[test]
+            pub fn compose_reflections_into_rotations() {
+                let (p1, p2) = (ONBasis::P(1), ONBasis::P(2));
+                let half = std::f64::consts::FRAC_1_SQRT_2;
+                let first = Versor::from_vectors(&[vector(vec![(1.0, p1)])]).unwrap();
+                let second = Versor::from_vectors(&[vector(vec![(half, p1), (half, p2)])]).unwrap();
+                let rotor = &second * &first;
+                assert!(rotor.is_rotor());
+                assert!(rotor.apply(&p1.to_mv()).approx_eq(&p2.to_mv(), 1e-9));
+                assert!(second.apply(&first.apply(&p2.to_mv())).approx_eq(&rotor.apply(&p2.to_mv()), 1e-9));
+                let same = Versor::from_bivector(&mv(vec![(-std::f64::consts::FRAC_PI_4, vec![p1, p2])])).unwrap();
+                assert!(same.apply(&p1.to_mv()).approx_eq(&p2.to_mv(), 1e-9));
+            }
+
+            #[test]
+            pub fn invert_and_normalize() {
+                let (p1, p2, p3, z0) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::Z(0));
+                let versor = Versor::new(&mv(vec![(3.0, vec![]), (1.0, vec![p1, p2]), (2.0, vec![p2, p3])])).unwrap();
+                let x = mv(vec![(1.0, vec![p1]), (2.0, vec![p2, p3]), (-1.0, vec![p3])]);
+                assert!(versor.inverse().apply(&versor.apply(&x)).approx_eq(&x, 1e-9));
+                let unit = versor.normalize().unwrap();
+                let norm = unit.to_mv() * unit.to_mv().reverse();
+                assert!(norm.approx_eq(&mv(vec![(1.0, vec![])]), 1e-9));
+                assert!(unit.apply(&x).approx_eq(&versor.apply(&x), 1e-9));
+                // translators in degenerate planes still normalize.
+                let translator = Versor::new(&mv(vec![(2.0, vec![]), (1.0, vec![z0, p1])])).unwrap();
+                assert!(translator.normalize().unwrap().to_mv().approx_eq(&mv(vec![(1.0, vec![]), (0.5, vec![z0, p1])]), 1e-9));
+            }
+
+            #[test]
+            pub fn reject_non_versors() {
+                let (p1, p2, p3, p4, z0) = (ONBasis::P(1), ONBasis::P(2), ONBasis::P(3), ONBasis::P(4), ONBasis::Z(0));
+                assert!(Versor::new(&Multivector::ZERO).is_err());
+                assert!(Versor::new(&mv(vec![(1.0, vec![]), (1.0, vec![p1])])).is_err());
+                assert!(Versor::new(&mv(vec![(1.0, vec![z0])])).is_err());
+                assert!(Versor::new(&mv(vec![(1.0, vec![]), (1.0, vec![p1, p2]), (1.0, vec![p3, p4])])).is_err());
+                assert!(Versor::from_vectors(&[vector(vec![(1.0, z0)])]).is_err());
+                assert!(Versor::from_bivector(&p1.to_mv()).is_err());
+            }
+        }
+    }
+
+    mod interpreter_tests {
+        mod parse_line_should {
+            use crate::{basis::ONBasis, component::Component, interpreter::Interpreter, multivector::Multivector};
+
+            #[test]
+            pub fn push_literals_onto_the_stack() {
+                let mut interpreter = Interpreter::new();
+                let result = interpreter.parse_line(&String::from("2 P(1) 3P(1)P(2)"))
+                    .expect("Literals failed.");
+                assert_eq!(result, "2\n1p1\n3p1p2");
+                // stack is cleared after every line.
+                assert_eq!(interpreter.stack.len(), 0);
+            }
+
+            #[test]
+            pub fn read_fraction_literals() {
+                let mut interpreter = Interpreter::new();
+                let result = interpreter.parse_line(&String::from("1/4P(1) 1/2 /"))
+                    .expect("Fractions failed.");
+                assert_eq!(result, "0.5p1");
+                // a lone / is still division.
+                let result = interpreter.parse_line(&String::from("1 4 /")).unwrap();
+                assert_eq!(result, "0.25");
+            }
+
+            #[test]
+            pub fn read_scientific_notation() {
+                let mut interpreter = Interpreter::new();
+                let result = interpreter.parse_line(&String::from("1e-3P(1) 2e3 *"))
+                    .expect("Exponents failed.");
+                assert_eq!(result, "2p1");
+            }
+
+            #[test]
+            pub fn apply_binary_operations() {
+                let mut interpreter = Interpreter::new();
+                let p1 = ONBasis::P(1);
+                let p2 = ONBasis::P(2);
+
+                interpreter.parse_line(&String::from("P(1) P(2) +")).unwrap();
+                assert_eq!(interpreter.priors.len(), 1);
+
+                // check results before the stack is cleared by running them directly.
+                let cases = vec![
+                    ("P(1) P(2) +", Multivector::new(vec![
+                        Component::new(1.0, vec![p1]), Component::new(1.0, vec![p2])])),
+                    ("P(1) P(2) -", Multivector::new(vec![
+                        Component::new(1.0, vec![p1]), Component::new(-1.0, vec![p2])])),
+                    ("P(1) P(2) *", Component::new(1.0, vec![p1, p2]).to_mv()),
+                    ("P(2) P(1) ^", Component::new(-1.0, vec![p1, p2]).to_mv()),
+                    ("P(1) P(1)P(2) >>", Component::new(1.0, vec![p2]).to_mv()),
+                    ("P(1)P(2) P(2) <<", Component::new(1.0, vec![p1]).to_mv()),
+                    ("P(1)P(2) 2P(2) /", Component::new(0.5, vec![p1]).to_mv()),
+                    ("3", Component::new(3.0, vec![]).to_mv()),
+                    ("3 +", Component::new(3.0, vec![]).to_mv()),
+                    ("3 -", Component::new(-3.0, vec![]).to_mv()),
+                ];
+                for (line, expected) in cases {
+                    let result = interpreter.parse_line(&String::from(line)).unwrap();
+                    assert_eq!(result, expected.to_string(), "Line '{}'", line);
+                }
+            }
+
+            #[test]
+            pub fn apply_unary_operations() {
+                let mut interpreter = Interpreter::new();
+                let cases = vec![
+                    ("2P(1) inverse", "0.5p1"),
+                    ("P(1)P(2) reverse", "-1p1p2"),
+                    ("P(1) involution", "-1p1"),
+                    ("P(1)P(2) involution", "1p1p2"),
+                    ("P(3)+P(1)P(2) generate_I", "1p1p2p3"),
+                    ("P(1) ~", "-1p1"),
+                    ("2P(1) mag", "4"),
+                    ("1+P(1)P(2) mag", "2"),
+                    ("P(1) P(1)P(2) dual", "-1p2"),
+                    ("P(2) P(1)P(2) undual", "-1p1"),
+                ];
+                for (line, expected) in cases {
+                    let result = interpreter.parse_line(&String::from(line)).unwrap();
+                    assert_eq!(result, expected, "Line '{}'", line);
+                }
+            }
+
+            #[test]
+            pub fn fail_on_bad_input() {
+                let mut interpreter = Interpreter::new();
+                interpreter.parse_line(&String::from("P(1) P(2) P(3) 1.2.3"))
+                    .expect_err("Bad token accepted.");
+                assert_eq!(interpreter.stack.len(), 0);
+                interpreter.parse_line(&String::from("P(1) P(2) P(3) notAssigned"))
+                    .expect_err("Unassigned Id accepted.");
+                assert_eq!(interpreter.stack.len(), 0);
+                interpreter.parse_line(&String::from("P(1) ^"))
+                    .expect_err("Missing value accepted.");
+                assert_eq!(interpreter.stack.len(), 0);
+                interpreter.parse_line(&String::from("P(1) Z(1) /"))
+                    .expect_err("Division by a degenerate accepted.");
+                interpreter.parse_line(&String::from("P(1) P(1)+P(2) dual"))
+                    .expect_err("Non-pseudoscalar accepted.");
+            }
+        }
+
+        mod parse_should {
+            use crate::interpreter::Interpreter;
+
+            #[test]
+            pub fn run_every_line() {
+                let mut interpreter = Interpreter::new();
+                interpreter.parse(&String::from("x 2P(1) =\ny x x * =; mode infix\nz = y + x"))
+                    .expect("Script failed.");
+                assert_eq!(interpreter.vars["z"].to_string(), "4 + 2p1");
+            }
+
+            #[test]
+            pub fn stop_at_the_first_error() {
+                let mut interpreter = Interpreter::new();
+                let err = interpreter.parse(&String::from("x 1 =\nx y +\nz 2 =")).unwrap_err();
+                assert_eq!(err, "Line 2: 'y' has not been assigned a value.");
+                assert!(!interpreter.vars.contains_key("z"));
+                // a single line has no line number.
+                let err = interpreter.parse(&String::from("P(1) ^")).unwrap_err();
+                assert_eq!(err, "'^' requires two values on the stack.");
+            }
+        }
+
+        mod basis_should {
+            use crate::{basis::ONBasis, interpreter::Interpreter};
+
+            #[test]
+            pub fn name_bases_for_reading_and_printing() {
+                let mut interpreter = Interpreter::new();
+                interpreter.parse_line(&String::from("x P(1) basis")).unwrap();
+                interpreter.parse_line(&String::from("y P(2) basis")).unwrap();
+                interpreter.parse_line(&String::from("t N(0) basis")).unwrap();
+                assert_eq!(interpreter.bases["t"], ONBasis::N(0));
+
+                let result = interpreter.parse_line(&String::from("3x y ^")).unwrap();
+                assert_eq!(result, "3xy");
+                let result = interpreter.parse_line(&String::from("3P(1)P(2)")).unwrap();
+                assert_eq!(result, "3xy");
+                let result = interpreter.parse_line(&String::from("t t *")).unwrap();
+                assert_eq!(result, "-1");
+            }
+
+            #[test]
+            pub fn reject_bad_declarations() {
+                let mut interpreter = Interpreter::new();
+                interpreter.parse_line(&String::from("x P(1) basis")).unwrap();
+                interpreter.parse_line(&String::from("x P(2) basis"))
+                    .expect_err("Renamed a basis.");
+                interpreter.parse_line(&String::from("z P(1) basis"))
+                    .expect_err("Two names for one basis.");
+                interpreter.parse_line(&String::from("z 2P(2) basis"))
+                    .expect_err("Named a non-basis.");
+                interpreter.parse_line(&String::from("v 2 =")).unwrap();
+                interpreter.parse_line(&String::from("v P(2) basis"))
+                    .expect_err("Named a variable.");
+                interpreter.parse_line(&String::from("x 2 ="))
+                    .expect_err("Assigned to a basis.");
+                assert_eq!(interpreter.bases.len(), 1);
+            }
+        }
+
+        mod assignment_should {
+            use crate::{basis::ONBasis, interpreter::Interpreter};
+
+            #[test]
+            pub fn bind_and_resolve_ids() {
+                let mut interpreter = Interpreter::new();
+                let result = interpreter.parse_line(&String::from("x 2P(1) =")).unwrap();
+                assert_eq!(result, "2p1");
+                assert_eq!(interpreter.vars["x"], ONBasis::P(1).to_mv() * 2.0);
+
+                // ids resolve on later lines.
+                let result = interpreter.parse_line(&String::from("x P(2) ^")).unwrap();
+                assert_eq!(result, "2p1p2");
+
+                // copies rather than links.
+                interpreter.parse_line(&String::from("y x =")).unwrap();
+                interpreter.parse_line(&String::from("x 3 =")).unwrap();
+                assert_eq!(interpreter.parse_line(&String::from("y")).unwrap(), "2p1");
+                assert_eq!(interpreter.parse_line(&String::from("x")).unwrap(), "3");
+
+                // assignments can be used within a line.
+                let result = interpreter.parse_line(&String::from("z 2 = z *")).unwrap();
+                assert_eq!(result, "4");
+            }
+
+            #[test]
+            pub fn reject_bad_assignments() {
+                let mut interpreter = Interpreter::new();
+                let err = interpreter.parse_line(&String::from("mag 2 ="))
+                    .expect_err("Assigned to reserved word.");
+                assert!(err.contains("reserved"), "{}", err);
+                interpreter.parse_line(&String::from("reverse P(1) ="))
+                    .expect_err("Assigned to reserved word.");
+                interpreter.parse_line(&String::from("2 P(1) ="))
+                    .expect_err("Assigned to a value.");
+                interpreter.parse_line(&String::from("x 1 +"))
+                    .expect_err("Used unassigned Id.");
+                assert!(interpreter.vars.is_empty());
+            }
+        }
+
+        mod infix_should {
+            use crate::interpreter::{Interpreter, Mode};
+
+            fn infix() -> Interpreter {
+                let mut interpreter = Interpreter::new();
+                interpreter.parse_line(&String::from("mode infix")).unwrap();
+                assert_eq!(interpreter.mode, Mode::Infix);
+                interpreter
+            }
+
+            #[test]
+            pub fn follow_precedence() {
+                let mut interpreter = infix();
+                let cases = vec![
+                    ("P(1) + P(1) * P(1)P(2) ^ P(3)", "1p1 + 1p2p3"),
+                    ("P(1) * P(1) >> P(1)P(2)", "1p1p2"),
+                    ("P(1)P(2) << P(2) ^ P(3)", "0"),
+                    ("2 * (P(1) + P(2))", "2p1 + 2p2"),
+                    ("1 - 2 - 3", "-4"),
+                    ("8 / 2 / 2", "2"),
+                    ("-P(1) ^ P(2)", "-1p1p2"),
+                    ("~P(1) - -P(2)", "-1p1 + 1p2"),
+                    ("1e-3P(1) * 1000", "1p1"),
+                    ("1/4P(1)", "0.25p1"),
+                    ("", ""),
+                ];
+                for (line, expected) in cases {
+                    let result = interpreter.parse_line(&String::from(line)).unwrap();
+                    assert_eq!(result, expected, "Line '{}'", line);
+                }
+            }
+
+            #[test]
+            pub fn call_functions() {
+                let mut interpreter = infix();
+                let cases = vec![
+                    ("reverse(P(1)P(2))", "-1p1p2"),
+                    ("dual(P(1), P(1)P(2))", "-1p2"),
+                    ("inverse(2P(1)) + mag(2P(1))", "4 + 0.5p1"),
+                    ("generate_I(P(3) + P(1))", "1p1p3"),
+                    ("basis(x, P(1))", "1x"),
+                    ("3x ^ P(2)", "3xp2"),
+                ];
+                for (line, expected) in cases {
+                    let result = interpreter.parse_line(&String::from(line)).unwrap();
+                    assert_eq!(result, expected, "Line '{}'", line);
+                }
+            }
+
+            #[test]
+            pub fn assign_and_switch_modes() {
+                let mut interpreter = infix();
+                assert_eq!(interpreter.parse_line(&String::from("a = b = P(1) + P(2)")).unwrap(), "1p1 + 1p2");
+                assert_eq!(interpreter.parse_line(&String::from("a * b")).unwrap(), "2");
+                interpreter.parse_line(&String::from("P(1) P(2) ^")).expect_err("RPN in infix.");
+
+                interpreter.parse_line(&String::from("mode rpn")).unwrap();
+                assert_eq!(interpreter.mode, Mode::Rpn);
+                assert_eq!(interpreter.parse_line(&String::from("a P(1) ^")).unwrap(), "-1p1p2");
+            }
+
+            #[test]
+            pub fn fail_on_bad_input() {
+                let mut interpreter = infix();
+                for line in [
+                    "P(1) +", "(P(1)", "P(1))", "P(1) P(2)", "reverse P(1)", "dual(P(1))",
+                    "P(1) + a = 3", "2 = 3", "notAssigned + 1", "P(1) / Z(1)", "* P(1)", "#",
+                ] {
+                    interpreter.parse_line(&String::from(line)).expect_err(line);
+                    assert_eq!(interpreter.stack.len(), 0);
+                }
+                let err = interpreter.parse_line(&String::from("mag = 2")).unwrap_err();
+                assert!(err.contains("reserved"), "{}", err);
+                assert!(interpreter.vars.is_empty());
+            }
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,378 @@
+use std::{collections::HashMap, fmt, ops::Range, str::FromStr};
+
+use crate::{basis::ONBasis, component::Component, multivector::Multivector, scalar::Scalar};
+
+/// # Parse Error
+///
+/// An error from reading a literal, along with the byte span of the text
+/// which caused it, so it can be pointed out to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong.
+    pub message: String,
+    /// The bytes of the literal at fault.
+    pub span: Range<usize>,
+}
+
+impl ParseError {
+    /// # New
+    pub fn new(message: String, span: Range<usize>) -> ParseError {
+        ParseError { message, span }
+    }
+}
+
+impl fmt::Display for ParseError {
+    /// Writes the message followed by the span, IE
+    /// 'Q(1)' does not start with a known basis. (at 2..6)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {}..{})", self.message, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(val: ParseError) -> Self {
+        val.to_string()
+    }
+}
+
+/// # Lexeme Kind
+///
+/// The kinds of token a literal is broken into.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// A number, IE 1, 1.5, .5, or 1e-3. The text is kept in the span.
+    Number,
+    /// A basis, either P(#), N(#), Z(#), or a name.
+    Basis(ONBasis),
+    Plus,
+    Minus,
+    Slash,
+    Open,
+    Close,
+}
+
+/// # Lexeme
+///
+/// A token of a literal and where it came from.
+#[derive(Debug, Clone, PartialEq)]
+struct Lexeme {
+    kind: Kind,
+    span: Range<usize>,
+}
+
+/// # Lex
+///
+/// Breaks a literal into lexemes, skipping whitespace.
+///
+/// Numbers may have an exponent, like 1e-3 or 2E10. As a basis may be named
+/// e1, an exponent without a sign is only read if no basis name covers the
+/// whole of it instead, so 2e1 is 2 e1 when e1 is a name, and 20 otherwise,
+/// even if e is a name.
+fn lex(src: &str, names: &HashMap<String, ONBasis>) -> Result<Vec<Lexeme>, ParseError> {
+    let bytes = src.as_bytes();
+    let mut result = vec![];
+    let mut pos = 0;
+    while pos < src.len() {
+        let start = pos;
+        let kind = match bytes[pos] {
+            x if x.is_ascii_whitespace() => {
+                pos += 1;
+                continue;
+            },
+            b'+' => Kind::Plus,
+            b'-' => Kind::Minus,
+            b'/' => Kind::Slash,
+            b'(' => Kind::Open,
+            b')' => Kind::Close,
+            x if x.is_ascii_digit() || x == b'.' => {
+                pos = lex_number(src, pos, names)?;
+                result.push(Lexeme { kind: Kind::Number, span: start..pos });
+                continue;
+            },
+            _ => {
+                let (basis, end) = lex_basis(src, pos, names)?;
+                pos = end;
+                result.push(Lexeme { kind: Kind::Basis(basis), span: start..pos });
+                continue;
+            },
+        };
+        pos += 1;
+        result.push(Lexeme { kind, span: start..pos });
+    }
+    Ok(result)
+}
+
+/// # Lex Number
+///
+/// Reads the number starting at pos, returning where it ends.
+fn lex_number(src: &str, pos: usize, names: &HashMap<String, ONBasis>) -> Result<usize, ParseError> {
+    let bytes = src.as_bytes();
+    let digits = |from: usize| from + bytes[from..].iter().take_while(|x| x.is_ascii_digit()).count();
+    let mut end = digits(pos);
+    if bytes.get(end) == Some(&b'.') {
+        end = digits(end + 1);
+    }
+    if bytes.get(end) == Some(&b'.') {
+        return Err(ParseError::new(String::from("Extra Decimal Found"), pos..end + 1));
+    }
+    // check for an exponent.
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let signed = matches!(bytes.get(end + 1), Some(b'+' | b'-'));
+        let exp_start = if signed { end + 2 } else { end + 1 };
+        let exp_end = digits(exp_start);
+        // a name only wins if all of e# is part of it.
+        let is_name = names.keys()
+            .any(|x| x.len() >= exp_end - end && src[end..].starts_with(x.as_str()));
+        if exp_end > exp_start && (signed || !is_name) {
+            end = exp_end;
+        }
+    }
+    Ok(end)
+}
+
+/// # Lex Basis
+///
+/// Reads the basis starting at pos, returning it and where it ends.
+///
+/// Unnamed bases are always P, N, or Z followed by (#), otherwise the
+/// longest name which fits is used, so a name may be followed by a group,
+/// like x(P(1) + P(2)).
+fn lex_basis(src: &str, pos: usize, names: &HashMap<String, ONBasis>) -> Result<(ONBasis, usize), ParseError> {
+    let rest = &src[pos..];
+    let digits = rest.bytes().skip(2).take_while(|x| x.is_ascii_digit()).count();
+    if matches!(rest.as_bytes(), [b'P' | b'N' | b'Z', b'(', ..]) && digits > 0 &&
+        rest.as_bytes().get(2 + digits) == Some(&b')') {
+        let end = pos + digits + 3;
+        return ONBasis::from_string(&String::from(&src[pos..end]))
+            .map(|basis| (basis, end))
+            .map_err(|msg| ParseError::new(msg, pos..end));
+    }
+    if let Some((name, basis)) = names.iter()
+        .filter(|(name, _)| rest.starts_with(name.as_str()))
+        .max_by_key(|(name, _)| name.len()) {
+        return Ok((*basis, pos + name.len()));
+    }
+    // anything else followed by ( was meant as a basis, so say what's wrong with it.
+    if rest.chars().nth(1) == Some('(') {
+        let Some(end) = rest.find(')') else {
+            return Err(ParseError::new(format!("'{}' is missing a closing ')'.", rest), pos..src.len()));
+        };
+        let end = pos + end + 1;
+        return ONBasis::from_string(&String::from(&src[pos..end]))
+            .map(|basis| (basis, end))
+            .map_err(|msg| ParseError::new(msg, pos..end));
+    }
+    // point at the whole word, or at least the character we stopped on.
+    let word = rest.find(|c: char| !c.is_alphanumeric())
+        .unwrap_or(rest.len())
+        .max(rest.chars().next().map_or(0, |c| c.len_utf8()));
+    Err(ParseError::new(format!("'{}' does not start with a known basis.", &rest[..word]), pos..pos + word))
+}
+
+/// # Parser
+///
+/// A recursive descent parser over the lexemes of a literal.
+///
+/// The grammar is
+///
+/// sum := ['+' | '-'] term (('+' | '-') term)*
+///
+/// term := [number ['/' number]] factor*, with at least one part
+///
+/// factor := basis | '(' sum ')'
+///
+/// where the factors of a term are multiplied together with the geometric
+/// product, so 2(P(1) + P(2))P(3) is 2P(1)P(3) + 2P(2)P(3).
+struct Parser<'a> {
+    src: &'a str,
+    lexemes: Vec<Lexeme>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str, names: &HashMap<String, ONBasis>) -> Result<Parser<'a>, ParseError> {
+        Ok(Parser { src, lexemes: lex(src, names)?, pos: 0 })
+    }
+
+    /// # Peek
+    ///
+    /// The kind of the next lexeme, if any.
+    fn peek(&self) -> Option<Kind> {
+        self.lexemes.get(self.pos).map(|x| x.kind)
+    }
+
+    /// # Span
+    ///
+    /// The span of the next lexeme, or the end of the text if there are none
+    /// left.
+    fn span(&self) -> Range<usize> {
+        self.lexemes.get(self.pos)
+            .map_or(self.src.len()..self.src.len(), |x| x.span.clone())
+    }
+
+    /// # Unexpected
+    ///
+    /// An error for the next lexeme, saying what we wanted instead.
+    fn unexpected(&self, expected: &str) -> ParseError {
+        match self.lexemes.get(self.pos) {
+            Some(lexeme) => ParseError::new(
+                format!("Expected {}, found '{}'.", expected, &self.src[lexeme.span.clone()]),
+                lexeme.span.clone()),
+            None => ParseError::new(format!("Expected {}, found the end.", expected), self.span()),
+        }
+    }
+
+    /// # Finish
+    ///
+    /// Checks that every lexeme has been used.
+    fn finish(&self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(Kind::Close) => Err(ParseError::new(String::from("Unmatched ')'."), self.span())),
+            Some(_) => Err(self.unexpected("'+' or '-'")),
+        }
+    }
+
+    /// # Sign
+    ///
+    /// Reads an optional leading sign, returning true if negative.
+    fn sign(&mut self) -> bool {
+        match self.peek() {
+            Some(Kind::Plus) => { self.pos += 1; false },
+            Some(Kind::Minus) => { self.pos += 1; true },
+            _ => false,
+        }
+    }
+
+    /// # Sum
+    fn sum<T: Scalar + FromStr>(&mut self) -> Result<Multivector<T>, ParseError> {
+        let mut result = if self.sign() { -self.term()? } else { self.term()? };
+        loop {
+            match self.peek() {
+                Some(Kind::Plus) => { self.pos += 1; result = result + self.term()?; },
+                Some(Kind::Minus) => { self.pos += 1; result = result - self.term()?; },
+                _ => return Ok(result),
+            }
+        }
+    }
+
+    /// # Term
+    fn term<T: Scalar + FromStr>(&mut self) -> Result<Multivector<T>, ParseError> {
+        let start = self.pos;
+        let mut result = Component::from_scalar(&self.coefficient()?).to_mv();
+        loop {
+            match self.peek() {
+                Some(Kind::Basis(basis)) => {
+                    self.pos += 1;
+                    result = result * Component::new(T::one(), vec![basis]);
+                },
+                Some(Kind::Open) => {
+                    let open = self.span();
+                    self.pos += 1;
+                    let group = self.sum()?;
+                    if self.peek() != Some(Kind::Close) {
+                        return Err(ParseError::new(String::from("Unclosed '('."), open));
+                    }
+                    self.pos += 1;
+                    result = result * group;
+                },
+                _ => break,
+            }
+        }
+        if self.pos == start {
+            return Err(self.unexpected("a number, basis, or '('"));
+        }
+        Ok(result)
+    }
+
+    /// # Component
+    ///
+    /// As Term, for a lone component, so with an optional sign but no groups.
+    fn component<T: Scalar + FromStr>(&mut self) -> Result<Component<T>, ParseError> {
+        let negative = self.sign();
+        let start = self.pos;
+        let mag: T = self.coefficient()?;
+        let mut bases = vec![];
+        while let Some(Kind::Basis(basis)) = self.peek() {
+            self.pos += 1;
+            bases.push(basis);
+        }
+        if self.pos == start {
+            return Err(self.unexpected("a number or basis"));
+        }
+        let mag = if negative { -mag } else { mag };
+        Ok(Component::new(mag, bases))
+    }
+
+    /// # Coefficient
+    ///
+    /// Reads an optional number or fraction of numbers, giving one if there
+    /// is none.
+    ///
+    /// The fraction is read as a whole, so for exact scalars like Rational,
+    /// 1/3P(1) is exactly a third, rather than 1 divided by 3 in floats.
+    fn coefficient<T: Scalar + FromStr>(&mut self) -> Result<T, ParseError> {
+        if self.peek() != Some(Kind::Number) {
+            return Ok(T::one());
+        }
+        let start = self.span().start;
+        let num = self.number()?;
+        if self.peek() != Some(Kind::Slash) {
+            return Ok(num);
+        }
+        self.pos += 1;
+        if self.peek() != Some(Kind::Number) {
+            return Err(self.unexpected("a denominator"));
+        }
+        let end = self.span().end;
+        let den = self.number::<T>()?;
+        if den.is_zero() {
+            return Err(ParseError::new(format!("'{}' divides by zero.", &self.src[start..end]), start..end));
+        }
+        Ok(num / den)
+    }
+
+    /// # Number
+    fn number<T: Scalar + FromStr>(&mut self) -> Result<T, ParseError> {
+        let span = self.span();
+        self.pos += 1;
+        self.src[span.clone()].parse::<T>()
+            .map_err(|_| ParseError::new(format!("'{}' is not a valid number.", &self.src[span.clone()]), span))
+    }
+}
+
+/// # Parse Multivector
+///
+/// Reads a multivector literal, like 1e-3P(1) - 2(x + P(2))y, where bases
+/// may be given by any name in names.
+///
+/// Whitespace is ignored, and the empty literal is zero.
+pub fn parse_multivector<T: Scalar + FromStr>(src: &str, names: &HashMap<String, ONBasis>) -> Result<Multivector<T>, ParseError> {
+    let mut parser = Parser::new(src, names)?;
+    if parser.peek().is_none() {
+        return Ok(Multivector::zero());
+    }
+    let result = parser.sum()?;
+    parser.finish()?;
+    Ok(result)
+}
+
+/// # Parse Component
+///
+/// Reads a single component, a number followed by any number of bases,
+/// like -1/3P(1)y.
+///
+/// Whitespace is ignored, and the empty literal is zero.
+pub fn parse_component<T: Scalar + FromStr>(src: &str, names: &HashMap<String, ONBasis>) -> Result<Component<T>, ParseError> {
+    let mut parser = Parser::new(src, names)?;
+    if parser.peek().is_none() {
+        return Ok(Component::zero());
+    }
+    let result = parser.component()?;
+    match parser.peek() {
+        None => Ok(result),
+        Some(_) => Err(parser.unexpected("a basis")),
+    }
+}
@@ -1,19 +1,33 @@
-use std::{collections::{HashMap, VecDeque}, env, io::{self, stdout}};
-use rusty_ga::{basis::ONBasis, multivector::Multivector};
+use std::{env, fs, io::{self, stdout}, process::ExitCode};
+use rusty_ga::interpreter::Interpreter;
 use std::io::prelude::*;
 
-pub mod blade;
-pub mod basis;
-pub mod component;
-pub mod multivector;
-
-fn main() {
-    let mut _bases: HashMap<String, ONBasis> = HashMap::new();
-    let mut _variables: HashMap<String, Multivector> = HashMap::new();
-    let mut _backlog: VecDeque<String> = VecDeque::new();
+fn main() -> ExitCode {
+    let mut interpreter = Interpreter::new();
 
     // Commandline args 1-N
-    let _args: Vec<String> = env::args().collect();
+    let args: Vec<String> = env::args().collect();
+    match args.get(1..).unwrap_or_default() {
+        [] => {},
+        [flag, expr] if flag == "-e" => return run(&mut interpreter, expr),
+        [flag] if flag == "-h" || flag == "--help" => {
+            print!("{}", USAGE_TEXT);
+            return ExitCode::SUCCESS;
+        },
+        [path] if !path.starts_with('-') => {
+            return match fs::read_to_string(path) {
+                Ok(script) => run(&mut interpreter, &script),
+                Err(err) => {
+                    eprintln!("Could not read '{}': {}", path, err);
+                    ExitCode::FAILURE
+                },
+            };
+        },
+        _ => {
+            eprint!("{}", USAGE_TEXT);
+            return ExitCode::from(2);
+        },
+    }
 
     // input lines
     print!(">>>");
@@ -21,68 +35,66 @@ fn main() {
     let stdin = io::stdin();
     for line in stdin.lock().lines() {
         if let Ok(vals) = line {
-            let res = process_input(vals);
-            if let Err(val) = res {
-                if val == "Quit" {
-                    break;
-                }
+            match process_input(&mut interpreter, vals) {
+                Ok(val) => if !val.is_empty() { println!("{}", val) },
+                Err(val) => if val == "Quit" { break; } else { println!("{}", val) },
             }
         }
         print!(">>>");
         stdout().flush().unwrap();
     }
+    ExitCode::SUCCESS
+}
+
+/// # Run
+/// 
+/// Runs a script or one-liner, printing each result to stdout and any error
+/// to stderr. Fails if any line does.
+fn run(interpreter: &mut Interpreter, text: &str) -> ExitCode {
+    match interpreter.parse(text) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(exception) => {
+            eprintln!("{}", exception);
+            ExitCode::FAILURE
+        },
+    }
 }
 
-pub fn process_input(inputs: String) -> Result<String, String> {
+pub fn process_input(interpreter: &mut Interpreter, inputs: String) -> Result<String, String> {
     let split = inputs.split_whitespace().collect::<Vec<_>>();
-    let first = split.get(0);
+    let first = split.first();
     if let Some(first_word) = first {
         if first_word.to_lowercase() == "help" ||
         first_word.to_lowercase() == "h" {
             print!("{}", HELP_TEXT);
+            return Ok(String::new());
         } else if first_word.to_lowercase() == "quit" ||
         first_word.to_lowercase() == "q" {
             return Err(String::from("Quit"));
-        } else if *first_word == "=" || // assign
-        *first_word == "+" || // addition
-        *first_word == "*" || // geometric product
-        *first_word == "/" || // Geometric Division
-        *first_word == ">>" || // Left Contraction
-        *first_word == "<<" || // Right Contraction
-        *first_word == "^" || // Outer Product
-        *first_word == "sel_grade" || // grade selection
-        *first_word == "inverse" || // Inversion
-        *first_word == "involution" || // Inversion
-        *first_word == "reverse" || // Inversion
-        *first_word == "dual" || // dual
-        *first_word == "undual" || // undual
-        *first_word == "generate_I" || // Generate Pseudoscalar
-        *first_word == "&" || // Minimum
-        *first_word == "|" || // Maximum
-        *first_word == "==" || // Equality
-        *first_word == "<" || // Less Than
-        *first_word == ">" || // Greater Than
-        *first_word == "<=" || // Less Than Or Equal
-        *first_word == ">=" || // Greater Than Or Equal
-        *first_word == "!=" || // Not Equal
-        *first_word == "mag" || // Magnitude
-        *first_word == "~" || // Negative/Negate
-        *first_word == "-" || // subtraction
-        *first_word == "is_blade?" // Is Blade?
-        {
-
         }
     }
 
-    Ok(String::from("No Comments"))
+    interpreter.parse(&inputs)
 }
 
+const USAGE_TEXT: &str = 
+"Usage: rusty_ga [script | -e line]
+With no arguments, starts an interactive session.
+script: Runs each line of the file, stopping at the first which fails.
+-e line: Runs the line given, IE rusty_ga -e \"P(1) P(2) ^\".
+Lines may also be split with ';'. Exits with a non-zero code if any line fails.
+";
+
 const HELP_TEXT: &str = 
-"System currently uses Postfix Notation for ease of management.
+"System uses Postfix Notation by default. Type 'mode infix' to switch to Infix Notation for the session, and 'mode rpn' to switch back.
+----------
+Infix Notation
+Operations go between their values, IE P(1) ^ P(2) rather than P(1) P(2) ^. Tightest first, the order is ^, then >> and <<, then * and /, then + and -, then =. Parentheses group as usual.
+~ or - before a value negates it. Operations which take no right hand value are called like functions, IE reverse(a), dual(a, I), and basis(x, P(1)).
 ----------
 Token Shorthands
 Id: A name for an variable, must start with a letter. Cannot share a name with a reserved word.
-Number: A number, including basis vectors.
+Number: A number, including basis vectors. May be a fraction, like 1/3P(1), or use an exponent, like 1e-3P(1), so long as it has no spaces.
 Basis: A specific basis, in the form P(#), N(#), or Z(#), where # is non-negative.
 Value: A number or already instantiated Id.
 ----------
@@ -102,6 +114,7 @@ reverse: Value reverse, returns the reversion of the blades in a multivector. IE
 dual: Value I Dual, returns the dual of the value with respect to a given pseudoscalar I, such that A dual = A >> I^-1
 undual: Value I Dual, returns the undual of the value with repect to a given Pseudoscalar I. Depending on the space, not all double duals equal the original value, as such, the undual guarantees that the original value is returned. Notes: In cases where the double dual doesn't equal the original blade, the 4 dual will. Both of these hold so long as no basis is degenerate (ie, no ONB::Z)
 generate I: value generate_I, returns a pseudoscalar which uses all the bases vectors contained in value.
+Declare Basis: Id Basis basis, names the basis so it can be written and printed by that name. IE, after x P(1) basis and y P(2) basis, 3P(1)P(2) can be written as 3xy.
 Equality: Value Value ==, returns +inf if values are equal, -inf otherwise.
 Not Equal: Value Value !=, returns the Negative of Equality
 Less Than: Value Value <, Returns +inf if true, -inf if false and NaN if it the values cannot be defined as such. Note: You can only compare multivectors which share all their components. In that case it compares their magnitudes.
@@ -1,21 +1,29 @@
-use std::{collections::HashSet, ops::{self, Add}};
+use std::{cmp::Ordering, collections::{HashMap, HashSet}, fmt, ops, str::FromStr};
 
-use crate::{blade::Blade, component::{self, Component}};
+use crate::{basis::ONBasis, blade::{Blade, Geometry}, component::Component, literal::{self, ParseError}, scalar::{Real, Scalar}, vector::Vector};
+
+/// # Zero Multivector
+/// 
+/// Module level shorthand for Multivector::ZERO.
+pub const ZERO: Multivector = Multivector::ZERO;
 
 /// # Multivector
 /// 
 /// A Multivector is a collection of k-vectors. 
 /// 
 /// It stores these vectors in the form of their components.
-/// while components are organized from lowest grade to highest, the order
-/// of bases within components are guranteed to be in order from lowest id
-/// to highest id. Any other ordering is not applied.
+/// Components are kept in canonical order, from lowest grade to highest, then
+/// by their bases (see canonical_order), with no two sharing the same bases.
+/// The order of bases within components are guranteed to follow ONBasis 
+/// ordering.
+/// 
+/// Keeping this order lets addition be a single merge of two lists.
 #[derive(Debug, Clone)]
-pub struct Multivector {
+pub struct Multivector<T: Scalar = f64> {
     /// The Component form of the Multivector
-    pub components: Vec<Component>,
+    pub components: Vec<Component<T>>,
     /// The blade form of the multivector.
-    pub blades: Vec<Blade>
+    pub blades: Vec<Blade<T>>
 }
 
 impl Multivector {
@@ -23,6 +31,70 @@ impl Multivector {
     /// 
     /// Has No components.
     pub const ZERO: Multivector = Multivector { components: vec![], blades: vec![] };
+}
+
+impl<T: Scalar> Multivector<T> {
+    /// # Zero
+    /// 
+    /// The multivector with no components, for any Scalar.
+    pub fn zero() -> Multivector<T> {
+        Multivector { components: vec![], blades: vec![] }
+    }
+
+    /// # New
+    /// 
+    /// Creates a new multivector from a list of components.
+    /// 
+    /// Like components are consolidated, zero components are dropped, and the 
+    /// result is put in canonical order.
+    /// 
+    /// Each component is kept as a blade of it's own. Use factor_blades to
+    /// group each grade into a single blade where possible.
+    pub fn new(components: Vec<Component<T>>) -> Multivector<T> {
+        Multivector::from_sorted(Multivector { components, blades: vec![] }
+            .consolidate_components()
+            .components)
+    }
+
+    /// # From Sorted
+    /// 
+    /// Creates a multivector from components which are already in canonical
+    /// order with no two sharing bases, IE the result of a merge. Only drops
+    /// zero components and fills in the blades, so is linear.
+    /// 
+    /// Also used by anything which only changes magnitudes, like reverse, as
+    /// that keeps the order.
+    fn from_sorted(mut components: Vec<Component<T>>) -> Multivector<T> {
+        components.retain(|x| !x.mag.is_zero());
+        let blades = components.iter().map(|x| x.to_blade()).collect();
+        Multivector { components, blades }
+    }
+
+    /// # Factor Blades
+    /// 
+    /// The same multivector, with blades filled by factoring each grade. 
+    /// Each grade which is a blade becomes one blade, otherwise each 
+    /// component is a blade of it's own.
+    /// 
+    /// This runs blade_breakdown on every grade, so is only done when asked.
+    pub fn factor_blades(&self) -> Multivector<T> {
+        let components = &self.components;
+        let mut blades = vec![];
+        let mut start = 0;
+        while start < components.len() {
+            let grade = components[start].grade();
+            let end = components[start..].iter()
+                .position(|x| x.grade() != grade)
+                .map_or(components.len(), |x| start + x);
+            let part = Multivector { components: components[start..end].to_vec(), blades: vec![] };
+            match part.blade_breakdown() {
+                Some(vectors) if part.len() > 1 => blades.push(Blade { components: part.components, vectors }),
+                _ => blades.extend(part.components.iter().map(|x| x.to_blade())),
+            }
+            start = end;
+        }
+        Multivector { components: components.clone(), blades }
+    }
 
     /// # Length
     /// 
@@ -31,6 +103,13 @@ impl Multivector {
         self.components.len()
     }
 
+    /// # Is Empty
+    /// 
+    /// Checks if the multivector has no components, IE is zero.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
     /// # Is Single Grade
     /// 
     /// Checks if the multivector is a single grade.
@@ -78,70 +157,110 @@ impl Multivector {
     /// A Multivector must have a single grade, if it has more than 1, it is
     /// by definition, not a blade, but a sum of k-blades.
     /// 
-    /// Likewise, the components of a multivector must overlap such that they all
-    /// have at least one basis in common between them. 
-    /// 
-    /// IE,
-    /// The multivector p1p2 + p2p3 is a blade because the two components 
-    /// share a basis p2.
-    /// 
-    /// The multivector p1p2 + p3p4 is not a blade because they do not share
-    /// a basis.
-    /// 
-    /// TODO: Come back here and rework this by using a multi-variable solution instead of this, which (I think) can be wrong.
-    // pub fn is_blade(&self) -> bool {
-    //     if !self.is_single_grade() {
-    //         return false;
-    //     }
-    //     // since it must be a single grade, get the first component and check it's grade.
-    //     let grade = self.components.first().unwrap_or(&component::ZERO).grade();
-    //     if grade < 2 { // if it's grade is less than 2 than it is, by definition, a blade.
-    //         return true;
-    //     }
-    //     // If there is only one or no component(s), that must be a blade.
-    //     if self.len() < 2 {
-    //         return true;
-    //     }
-    //     // For items to add together, they must share a basis, connecting all of them together.
-    //     // To ensure they all connect together either directly or indirectly, 
-    //     // we group them together using the groups vec. The idx in group is the
-    //     // idx of the component. 
-    //     // we initialize them to zero, then set the first as group 1
-    //     let mut groups = vec![vec![]; self.components.len()];
-    //     for (curr_idx, comp) in self.components.iter().enumerate() {
-    //         for (oidx, other) in self.components.iter().enumerate() {
-    //             if comp.bases().iter().any(|x| other.bases().contains(x)) {
-    //                 groups[curr_idx].push(oidx);
-    //             }
-    //         }
-    //     }
-    //     // with connections found, connect and see if all are in the same group.
-    //     let mut stack = vec![];
-    //     stack.push(0);
-    //     let mut reached_from_zero = HashSet::new();
-    //     while let Some(curr) = stack.pop() {
-    //         for &idx in groups[curr].iter() {
-    //             if reached_from_zero.insert(idx) {
-    //                 stack.push(idx);
-    //             }
-    //         }
-    //     }
-    //     // If we mapped out everything, then we should have the same length in set and components
-    //     return reached_from_zero.len() == self.components.len();
-    // }
-
-    // /// # Blade Breakdown
-    // /// 
-    // /// Breakdown takes takes the multivector and decomposes it into vectors that, when their outer product
-    // /// is taken, produce the resulting blade.
-    // /// 
-    // /// If this multivector is not a blade, it returns an empty vector.
-    // pub fn blade_breakdown(&self) -> Vec<Multivector> {
-    //     if !self.is_blade() { return vec![]; }
-    //     // since we are a blade, begin breaking it down
-    //     // look for any common simple vectors first.
-    //     todo!()
-    // }
+    /// A k-vector B is a blade exactly when the vectors v where v ^ B = 0 
+    /// form a space of k dimensions. Those vectors are the factors of B. If B
+    /// is not a blade, that space is smaller.
+    /// 
+    /// This is found numerically, so values within BLADE_TOLERANCE of the 
+    /// largest magnitude are treated as zero.
+    /// 
+    /// Zero is considered a blade of any grade.
+    pub fn is_blade(&self) -> bool {
+        if !self.is_single_grade() {
+            return false;
+        }
+        // since it must be a single grade, get the first component and check it's grade.
+        let grade = self.components.first().map_or(0, |x| x.grade());
+        // If it's grade is less than 2 or there is only one or no component(s), 
+        // that must be a blade.
+        if grade < 2 || self.len() < 2 {
+            return true;
+        }
+        self.blade_space().len() == grade
+    }
+
+    /// # Blade Breakdown
+    /// 
+    /// Breakdown takes takes the multivector and decomposes it into vectors that, when their outer product
+    /// is taken, produce the resulting blade.
+    /// 
+    /// The magnitude of the blade is carried by the first vector.
+    /// 
+    /// If this multivector is not a blade, or is zero, it returns None. Scalars
+    /// break down into no vectors at all.
+    pub fn blade_breakdown(&self) -> Option<Vec<Vector<T>>> {
+        if self.components.is_empty() || !self.is_blade() {
+            return None;
+        }
+        let grade = self.components[0].grade();
+        if grade == 0 {
+            return Some(vec![]);
+        }
+        if grade == 1 {
+            return Some(vec![Vector::new(&self.components)]);
+        }
+        if self.len() == 1 {
+            return Some(self.components[0].vector_decomposition());
+        }
+        let mut vectors = self.blade_space();
+        // The outer product of our vectors matches self up to scale, so scale
+        // the first by comparing the largest component of each.
+        let largest = self.components.iter()
+            .max_by(|a, b| a.mag.magnitude().total_cmp(&b.mag.magnitude()))
+            .unwrap();
+        let product = Blade::new(&vectors).components;
+        let scale = product.iter()
+            .find(|x| x.same_bases(largest))
+            .map(|x| largest.mag.clone() / x.mag.clone())?;
+        vectors[0] = vectors[0].scalar_mult(scale);
+        Some(vectors)
+    }
+
+    /// # Blade Space
+    /// 
+    /// Finds a basis for the vectors v where v ^ self = 0, only looking at the
+    /// bases self uses. Any vector using other bases can't be in it.
+    /// 
+    /// Assumes self is a single grade.
+    fn blade_space(&self) -> Vec<Vector<T>> {
+        let mut bases: Vec<ONBasis> = vec![];
+        for comp in self.components.iter() {
+            for basis in comp.bases() {
+                if !bases.contains(basis) {
+                    bases.push(*basis);
+                }
+            }
+        }
+        bases.sort();
+        // each column is basis ^ self, each row is one of the resulting blades.
+        let mut rows: Vec<Vec<ONBasis>> = vec![];
+        let mut matrix: Vec<Vec<T>> = vec![];
+        for (col, basis) in bases.iter().enumerate() {
+            let vector = Component::new(T::one(), vec![*basis]);
+            for comp in self.components.iter() {
+                let prod = vector.outer_product(comp);
+                if prod.mag.is_zero() {
+                    continue;
+                }
+                let row = match rows.iter().position(|x| x == prod.bases()) {
+                    Some(row) => row,
+                    None => {
+                        rows.push(prod.bases().to_vec());
+                        matrix.push(vec![T::zero(); bases.len()]);
+                        rows.len() - 1
+                    }
+                };
+                matrix[row][col] = matrix[row][col].clone() + prod.mag;
+            }
+        }
+        null_space(matrix, bases.len())
+            .into_iter()
+            .map(|vals| Vector::new(&bases.iter().zip(vals)
+                .filter(|(_, val)| !val.is_zero())
+                .map(|(basis, val)| Component::new(val, vec![*basis]))
+                .collect()))
+            .collect()
+    }
 
     /// # Consolidate Components
     /// 
@@ -158,23 +277,21 @@ impl Multivector {
     /// 
     /// Adding or multiplying components into a Multivector should consolidate them 
     /// implicitly.
-    pub fn consolidate_components(&self) -> Multivector {
-        let mut result: Vec<Component> = vec![];
-        for component in self.components.iter() {
-            let mut added = false;
-            for res_comp in result.iter_mut() {
-                if res_comp.same_bases(component) {
-                    res_comp.mag += component.mag;
-                    added = true;
-                    break;
-                }
-            }
-            if !added {
-                result.push(component.clone());
+    /// 
+    /// The result is in canonical order, found by sorting, so like components
+    /// end up next to each other. Components which sum to zero are kept.
+    pub fn consolidate_components(&self) -> Multivector<T> {
+        let mut sorted = self.components.clone();
+        sorted.sort_by(canonical_order);
+        let mut result: Vec<Component<T>> = Vec::with_capacity(sorted.len());
+        for component in sorted {
+            match result.last_mut() {
+                Some(last) if last.same_bases(&component) => last.mag = last.mag.clone() + component.mag,
+                _ => result.push(component),
             }
         }
 
-        Multivector { components: result }
+        Multivector { components: result, blades: vec![] }
     }
 
     /// # Add Component
@@ -183,36 +300,23 @@ impl Multivector {
     /// 
     /// If the resulting component is zero, it removes it from the 
     /// resulting multivector.
-    pub fn component_add(&self, rhs: &Component) -> Multivector {
+    pub fn component_add(&self, rhs: &Component<T>) -> Multivector<T> {
         // if the right hand size is zero, then skip adding.
-        if rhs.mag == 0.0 {
+        if rhs.mag.is_zero() {
             return self.clone();
         }
-        // get the grade and split off those parts
-        let mut result = vec![];
-        let mut contracted = false;
-        for comp in self.components.iter() {
-            if contracted { 
-                // if we've already added to a component, just add 
-                // to the result and continue.
-                result.push(comp.clone());
-                continue;
-            }
-            if let Some(val) = comp.force_comp_add(rhs) {
-                // if it added, then we have a contraction
-                if val.mag == 0.0 {
-                    // only include if it's adds to non-zero
-                    result.push(val);
+        // find where it belongs, adding to a match if there is one.
+        let mut result = self.components.clone();
+        match result.binary_search_by(|x| canonical_order(x, rhs)) {
+            Ok(idx) => {
+                result[idx].mag = result[idx].mag.clone() + rhs.mag.clone();
+                if result[idx].mag.is_zero() { // drop it if it cancelled out.
+                    result.remove(idx);
                 }
-                contracted = true;
-            } else { // if no value returned, then keep the original.
-                result.push(comp.clone());
-            }
+            },
+            Err(idx) => result.insert(idx, rhs.clone()),
         }
-        if !contracted { // if no addition at any time, add to end.
-            result.push(rhs.clone());
-        }
-        Multivector::new(result)
+        Multivector::from_sorted(result)
     }
 
     /// # Base Add
@@ -222,32 +326,35 @@ impl Multivector {
     /// A simple add method. Any like components are combined, 
     /// if they add to 0, they are removed.
     /// 
-    /// TODO consider improving to reduce computation cost.
-    pub fn multivector_add(&self, rhs: &Multivector) -> Multivector {
-        let mut result = self.clone();
-        for comp in rhs.components.iter() {
-            result = result + comp;
-        }
-        result
+    /// As both sides are in canonical order, this is a single merge.
+    pub fn multivector_add(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        Multivector::from_sorted(merge(&self.components, &rhs.components, T::one()))
+    }
+
+    /// # Base Subtract
+    /// 
+    /// As Base Add, but subtracting rhs, without negating it first.
+    pub fn multivector_sub(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        Multivector::from_sorted(merge(&self.components, &rhs.components, -T::one()))
     }
 
     /// # Scalar Add
     /// 
     /// Multivector Addition between a Multivector and a Scalar value.
-    pub fn scalar_add(&self, rhs: &f64) -> Multivector {
-        self.component_add(&Component::from_float(rhs))
+    pub fn scalar_add(&self, rhs: &T) -> Multivector<T> {
+        self.component_add(&Component::from_scalar(rhs))
     }
 
     /// # Take Grade
     /// 
     /// Takes those parts of a multivector of a particular grade.
-    pub fn take_grade(&self, grade: usize) -> Multivector {
+    pub fn take_grade(&self, grade: usize) -> Multivector<T> {
         let mut result = vec![];
         for comp in self.components.iter()
         .filter(|x| x.grade() == grade) {
             result.push(comp.clone());
         }
-        Multivector::new(result)
+        Multivector::from_sorted(result)
     }
 
     /// # Component Geometric Product
@@ -255,15 +362,12 @@ impl Multivector {
     /// Does geometric product between a multivector and a component.
     /// 
     /// Multiplies the component with all components in the multivector.
-    pub fn geo_prod_comp(&self, rhs: &Component) -> Multivector {
-        let mut result = ZERO;
+    pub fn geo_prod_comp(&self, rhs: &Component<T>) -> Multivector<T> {
+        let mut result = Vec::with_capacity(self.len());
         for comp in self.components.iter() {
-            let temp = comp * rhs;
-            if temp.mag != 0.0 {
-                result = result + temp;
-            }
+            result.push(comp * rhs);
         }
-        result
+        Multivector::new(result)
     }
 
     /// # Geometric Product without adding
@@ -272,8 +376,8 @@ impl Multivector {
     /// 
     /// This is useful for various proofs that need to look at all results of the
     /// geometric product.
-    pub fn geo_product_no_adding(&self, rhs: &Multivector) -> Vec<Component> {
-        let mut result = vec![];
+    pub fn geo_product_no_adding(&self, rhs: &Multivector<T>) -> Vec<Component<T>> {
+        let mut result = Vec::with_capacity(self.len() * rhs.len());
         for l_c in self.components.iter() {
             for r_c in rhs.components.iter() {
                 result.push(l_c * r_c);
@@ -285,53 +389,88 @@ impl Multivector {
     /// # Multivector Geometric Product
     /// 
     /// Geometric Product between two multivectors.
-    pub fn geo_prod_mv(&self, rhs: &Multivector) -> Multivector {
-        let mut accumulator = ZERO;
-        for comp in rhs.components.iter() {
-            let temp = self.geo_prod_comp(comp);
-            accumulator = accumulator + temp;
-        }
-        accumulator
+    pub fn geo_prod_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        Multivector::new(self.geo_product_no_adding(rhs))
     }
 
     /// # Magnitude Squared
     /// 
     /// Gets the Magnitude of the multivector squared.
     /// 
-    /// Multiplies self.inverse * self .take grade 0
-    pub fn norm_sqrd(&self) -> Option<f64> {
-        // multiply self by it's inverse, take grade 0, then get the magnitude
-        if let Some(inv) = self.inverse() {
-            Some((inv * self).take_grade(0).components[0].mag)
-        } else {
-            None
-        }
+    /// Multiplies self * self.reverse() and takes grade 0. For blades and 
+    /// versors, this is the same as self * self.inverse() scaled back up.
+    /// 
+    /// Returns None exactly when the multivector has no inverse, as found by
+    /// inverse, IE for multivectors like Z(0) or 1 + P(1). This costs a
+    /// solve, so prefer (self * self.reverse()) directly when the multivector
+    /// is known to be a blade or versor.
+    pub fn norm_sqrd(&self) -> Option<T> {
+        self.inverse()?;
+        Some((self * self.reverse()).take_grade(0).components.first().map_or(T::zero(), |x| x.mag.clone()))
     }
 
     /// # Scalar Multiplication
     /// 
-    /// Exactly what it says. Only speical case is that if rhs is 0.0, then
+    /// Exactly what it says. Only speical case is that if rhs is 0, then
     /// it returns the Zero Multivector.
-    pub fn scalar_mult(&self, rhs: f64) -> Multivector {
-        if rhs == 0.0 {
-            return ZERO;
+    pub fn scalar_mult(&self, rhs: T) -> Multivector<T> {
+        if rhs.is_zero() {
+            return Multivector::zero();
         }
         let mut result = vec![];
         for comp in self.components.iter() {
-            result.push(comp.clone() * rhs);
+            result.push(comp * rhs.clone());
         }
-        Multivector::new(result)
+        Multivector::from_sorted(result)
     }
 
     /// # Negative 
     /// 
     /// Negates the Multivector.
-    pub fn negative(&self) -> Multivector {
+    pub fn negative(&self) -> Multivector<T> {
         let mut result = vec![];
         for comp in self.components.iter() {
             result.push(-comp);
         }
-        Multivector::new(result)
+        Multivector::from_sorted(result)
+    }
+
+    /// # Reverse
+    /// 
+    /// Reverses every component of the multivector, which flips the sign of
+    /// each grade in the pattern ++--++--...
+    /// 
+    /// Also available as !mv.
+    pub fn reverse(&self) -> Multivector<T> {
+        let mut result = vec![];
+        for comp in self.components.iter() {
+            result.push(comp.reversion());
+        }
+        Multivector::from_sorted(result)
+    }
+
+    /// # Grade Involution
+    /// 
+    /// Involutes every component of the multivector, which flips the sign of
+    /// each grade in the pattern +-+-+-...
+    pub fn grade_involution(&self) -> Multivector<T> {
+        let mut result = vec![];
+        for comp in self.components.iter() {
+            result.push(comp.involution());
+        }
+        Multivector::from_sorted(result)
+    }
+
+    /// # Clifford Conjugate
+    /// 
+    /// The reverse of the grade involution, which flips the sign of each grade
+    /// in the pattern +--++--+...
+    pub fn clifford_conjugate(&self) -> Multivector<T> {
+        let mut result = vec![];
+        for comp in self.components.iter() {
+            result.push(comp.reversion().involution());
+        }
+        Multivector::from_sorted(result)
     }
 
     /// # Float Outer Product
@@ -339,105 +478,645 @@ impl Multivector {
     /// Outer Product between a multivector and a scalar.
     /// 
     /// Equivalent to scalar mult.
-    pub fn outer_prod_scalar(&self, rhs: f64) -> Multivector {
+    pub fn outer_prod_scalar(&self, rhs: T) -> Multivector<T> {
         self.scalar_mult(rhs)
     }
 
     /// # Component Outer Product
     /// 
     /// Outer Product between a multivector and a component.
-    pub fn outer_prod_comp(&self, rhs: &Component) -> Multivector {
-        let mut result = ZERO;
+    pub fn outer_prod_comp(&self, rhs: &Component<T>) -> Multivector<T> {
+        let mut result = Vec::with_capacity(self.len());
         for comp in self.components.iter() {
-            result = result + (comp ^ rhs);
+            result.push(comp ^ rhs);
         }
-        result
+        Multivector::new(result)
     }
 
     /// # Multivector Outer Product
     /// 
     /// Takes the Outer Product between two multivectors
-    pub fn outer_product_mv(&self, rhs: &Multivector) -> Multivector {
-        let mut result = ZERO;
+    pub fn outer_product_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        let mut result = Vec::with_capacity(self.len() * rhs.len());
         for lcomp in self.components.iter() {
-            result = result + lcomp ^ rhs;
+            for rcomp in rhs.components.iter() {
+                result.push(lcomp ^ rcomp);
+            }
         }
-        result
+        Multivector::new(result)
+    }
+
+    /// # Regressive Product
+    ///
+    /// The regressive (vee) product, A v B, dual to the outer product. Where
+    /// the outer product spans the union of two subspaces, the regressive
+    /// product gives their intersection, provided together they span the
+    /// whole geometry.
+    ///
+    /// When the pseudoscalar I is invertible, this is found via duality, as
+    /// I (A~ I ^ B~ I)~. Here A~ I is the dual of A, up to the sign A A~,
+    /// and those signs cancel out. When the metric is degenerate, the dual
+    /// loses information, so the complements of the geometry are used
+    /// instead, ∁^-1(∁A ^ ∁B). The two agree wherever both exist.
+    /// 
+    /// The result is cleaned with the geometry's zero threshold, if it has one.
+    pub fn regressive(&self, rhs: &Multivector<T>, geometry: &Geometry) -> Multivector<T> {
+        let pseudoscalar = geometry.pseudoscalar();
+        let pseudoscalar = Component::new(T::from_f64(pseudoscalar.mag), pseudoscalar.bases);
+        if pseudoscalar.inverse().is_none() {
+            return geometry.clean(&geometry.inverse_complement(
+                &(geometry.complement(self) ^ geometry.complement(rhs))));
+        }
+        let lhs = self.reverse() * &pseudoscalar;
+        let rhs = rhs.reverse() * &pseudoscalar;
+        geometry.clean(&(pseudoscalar * (lhs ^ rhs).reverse()))
+    }
+
+    /// # Component Left Contraction
+    ///
+    /// Left Contraction between a multivector and a component, the component
+    /// on the right.
+    /// 
+    /// Each component of the multivector is contracted onto the component and 
+    /// the results summed, so any part of a higher grade than the component 
+    /// drops out.
+    pub fn left_cont_comp(&self, rhs: &Component<T>) -> Multivector<T> {
+        let mut result = vec![];
+        for comp in self.components.iter() {
+            result.push(comp.left_cont(rhs));
+        }
+        Multivector::new(result)
+    }
+
+    /// # Component Right Contraction
+    /// 
+    /// Right Contraction between a multivector and a component, the component
+    /// on the right.
+    /// 
+    /// As Left Contraction, but any part of a lower grade than the component 
+    /// drops out.
+    pub fn right_cont_comp(&self, rhs: &Component<T>) -> Multivector<T> {
+        let mut result = vec![];
+        for comp in self.components.iter() {
+            result.push(comp.right_cont(rhs));
+        }
+        Multivector::new(result)
+    }
+
+    /// # Multivector Left Contraction
+    /// 
+    /// Left Contraction between two multivectors. Removes lhs from rhs.
+    /// 
+    /// Bilinear, so for each pair of components, the grade rhs - lhs part of 
+    /// their product is kept, and the pair is dropped if lhs has the higher
+    /// grade.
+    pub fn left_cont_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        let mut result = vec![];
+        for lcomp in self.components.iter() {
+            for rcomp in rhs.components.iter() {
+                result.push(lcomp.left_cont(rcomp));
+            }
+        }
+        Multivector::new(result)
+    }
+
+    /// # Multivector Right Contraction
+    /// 
+    /// Right Contraction between two multivectors. Removes rhs from lhs.
+    /// 
+    /// Bilinear, so for each pair of components, the grade lhs - rhs part of 
+    /// their product is kept, and the pair is dropped if rhs has the higher
+    /// grade.
+    pub fn right_cont_mv(&self, rhs: &Multivector<T>) -> Multivector<T> {
+        let mut result = vec![];
+        for lcomp in self.components.iter() {
+            for rcomp in rhs.components.iter() {
+                result.push(lcomp.right_cont(rcomp));
+            }
+        }
+        Multivector::new(result)
     }
 
     /// # Multivector Inverse
     /// 
     /// Inverts the multivector, same as taking it to the power -1.
     /// 
-    /// Returns None if Multivector has any factor which is not invertable.
-    pub fn inverse(&self) -> Option<Multivector> {
-        let mut result = ZERO;
+    /// Returns None if the Multivector is not invertable.
+    /// 
+    /// ## Logic
+    /// 
+    /// Single components are inverted directly.
+    /// 
+    /// Otherwise, we take the n bases the multivector uses and write 
+    /// multiplication by self as a 2^n by 2^n matrix over the basis blades of
+    /// those bases. The inverse is the solution to self * x = 1, which exists
+    /// only if that matrix is not singular. This works in any signature, 
+    /// including degenerate bases, such as 1 + Z(0), which has the inverse
+    /// 1 - Z(0).
+    /// 
+    /// The inverse of an element always lives in the subalgebra of the bases
+    /// it uses, so we need not look further than those.
+    pub fn inverse(&self) -> Option<Multivector<T>> {
+        match self.components.as_slice() {
+            [] => return None,
+            [comp] => return comp.inverse().map(|x| x.to_mv()),
+            _ => {}
+        }
+        // the bases used, in order, and every blade we can make from them.
+        let mut bases: Vec<ONBasis> = vec![];
         for comp in self.components.iter() {
-            if let Some(inv) = comp.inverse() {
-                result = result + inv;
-            } else {
-                return None;
+            for basis in comp.bases() {
+                if !bases.contains(basis) {
+                    bases.push(*basis);
+                }
             }
         }
-        Some(result)
+        bases.sort();
+        let size = 1_usize << bases.len();
+        let blade = |mask: usize| Component::new(T::one(), bases.iter().enumerate()
+            .filter(|(idx, _)| mask & (1 << idx) != 0)
+            .map(|(_, b)| *b)
+            .collect());
+        let mask_of = |comp: &Component<T>| comp.bases().iter()
+            .map(|b| 1 << bases.iter().position(|x| x == b).unwrap())
+            .sum::<usize>();
+        // Build the matrix of self * blade, blade by blade.
+        let mut matrix = vec![vec![T::zero(); size + 1]; size];
+        for (col, col_blade) in (0..size).map(blade).enumerate() {
+            for comp in self.components.iter() {
+                let prod = comp * &col_blade;
+                if !prod.mag.is_zero() {
+                    let row = mask_of(&prod);
+                    matrix[row][col] = matrix[row][col].clone() + prod.mag;
+                }
+            }
+        }
+        // we want self * x = 1.
+        matrix[0][size] = T::one();
+        let solution = solve(matrix)?;
+        let mut result = vec![];
+        for (mask, val) in solution.into_iter().enumerate() {
+            result.push(blade(mask) * val);
+        }
+        Some(Multivector::new(result))
+    }
+
+    /// # Multivector Division
+    /// 
+    /// Geometric Product of self with the inverse of rhs. IE, self * rhs^-1.
+    /// 
+    /// Returns None if rhs has no inverse.
+    pub fn div_mv(&self, rhs: &Multivector<T>) -> Option<Multivector<T>> {
+        Some(self * rhs.inverse()?)
     }
 
-    pub fn components(&self) -> &[Component] {
+    /// # Approximately Equal
+    /// 
+    /// Checks if two multivectors are equal to within eps, IE every 
+    /// component of their difference has a magnitude of at most eps.
+    /// 
+    /// Use this over == when rounding may have crept in, as == compares 
+    /// magnitudes exactly.
+    pub fn approx_eq(&self, other: &Multivector<T>, eps: f64) -> bool {
+        self.multivector_sub(other).components.iter()
+            .all(|x| x.mag.magnitude() <= eps)
+    }
+
+    /// # Clean
+    /// 
+    /// Removes any components with a magnitude of at most eps, such as the 
+    /// 1e-17 left behind after a few rotations.
+    pub fn clean(&self, eps: f64) -> Multivector<T> {
+        Multivector::from_sorted(self.components.iter()
+            .filter(|x| x.mag.magnitude() > eps)
+            .cloned()
+            .collect())
+    }
+
+    pub fn components(&self) -> &[Component<T>] {
         self.components.as_ref()
     }
 
-    pub fn to_string(&self) -> String {
+    /// # To String Named
+    /// 
+    /// Converts the multivector to a string, writing any bases found in names
+    /// by their name instead.
+    pub fn to_string_named(&self, names: &HashMap<String, ONBasis>) -> String {
+        if self.components.is_empty() {
+            return String::from("0");
+        }
         let mut result = String::new();
         for comp in self.components.iter() {
-            result.push_str(comp.to_string().as_str());
+            result.push_str(comp.to_string_named(names).as_str());
             result.push_str(" + ");
         }
         result.truncate(result.len()-3);
         result
     }
+}
+
+impl<T: Real> Multivector<T> {
+    /// # Exponential
+    /// 
+    /// Takes e to the power of the multivector. For a bivector B, this gives
+    /// the rotor which rotates by twice the angle of B in it's plane. IE 
+    /// exp(-θ/2 p1p2) rotates by θ from p1 towards p2 in a sandwich.
+    /// 
+    /// ## Logic
+    /// 
+    /// A scalar part commutes with everything, so exp(a + B) = e^a exp(B).
+    /// If B squares to a scalar, as simple bivectors do, a closed form is
+    /// used based on the sign of that square.
+    /// 
+    /// - B^2 < 0 (a P or N plane): cos(θ) + B/θ sin(θ), where θ^2 = -B^2.
+    /// - B^2 > 0 (a mixed P and N plane): cosh(θ) + B/θ sinh(θ), where θ^2 = B^2.
+    /// - B^2 = 0 (a plane with a Z basis): 1 + B.
+    /// 
+    /// Anything else falls back to the power series, after halving self
+    /// enough times that it converges quickly, then squaring it back up.
+    pub fn exp(&self) -> Multivector<T> {
+        let scalar = self.take_grade(0).as_scalar().unwrap_or(T::zero());
+        let rest = self - scalar.clone();
+        let Some(sqr) = (&rest * &rest).as_scalar() else {
+            return self.exp_series();
+        };
+        let (cos, sin) = if sqr.magnitude() <= CLOSED_FORM_TOLERANCE {
+            (T::one(), T::one())
+        } else if sqr < T::zero() {
+            let theta = (-sqr).sqrt();
+            (theta.cos(), theta.sin() / theta)
+        } else {
+            let theta = sqr.sqrt();
+            (theta.cosh(), theta.sinh() / theta)
+        };
+        (rest * sin + cos) * scalar.exp()
+    }
+
+    /// # Exponential Series
+    /// 
+    /// Sums the power series of exp, scaling down first so it converges and
+    /// squaring back up after.
+    fn exp_series(&self) -> Multivector<T> {
+        let halvings = self.abs_sum().log2().ceil().max(0.0) as i32 + 1;
+        let scaled = self * T::from_f64(0.5_f64.powi(halvings));
+        let mut result = Component::new(T::one(), vec![]).to_mv();
+        let mut term = result.clone();
+        for k in 1..SERIES_LIMIT {
+            term = &term * &scaled * T::from_f64(1.0 / k as f64);
+            result = result + &term;
+            if term.abs_sum() <= f64::EPSILON * result.abs_sum() {
+                break;
+            }
+        }
+        for _ in 0..halvings {
+            result = &result * &result;
+        }
+        result
+    }
+
+    /// # Logarithm
+    /// 
+    /// The inverse of exp, giving the bivector (plus scalar) of a rotor.
+    /// 
+    /// Returns None if there is no real logarithm, such as for a rotation
+    /// of exactly 2π (-1) or a hyperbolic rotor with a negative scalar part.
+    /// 
+    /// ## Logic
+    /// 
+    /// For a + B, where B^2 is a scalar s, we have (a + B)(a - B) = a^2 - s = ρ^2,
+    /// so a + B = ρ exp(φ B/|B|) and the log is ln(ρ) + φ B/|B|, where
+    /// 
+    /// - s < 0: φ = atan2(|B|, a).
+    /// - s > 0: φ = atanh(|B|/a), needing a > |B|.
+    /// - s = 0: φ B/|B| = B/a, needing a > 0.
+    /// 
+    /// Anything else takes square roots until close to 1, then sums the 
+    /// series of log(1 + x).
+    pub fn log(&self) -> Option<Multivector<T>> {
+        let scalar = self.take_grade(0).as_scalar().unwrap_or(T::zero());
+        let rest = self - scalar.clone();
+        let Some(sqr) = (&rest * &rest).as_scalar() else {
+            return self.log_series();
+        };
+        if rest.components.is_empty() {
+            return if scalar > T::zero() { Some(Component::new(scalar.ln(), vec![]).to_mv()) } else { None };
+        }
+        let norm_sqrd = scalar.clone() * scalar.clone() - sqr.clone();
+        if norm_sqrd <= T::zero() {
+            return None;
+        }
+        let ln_norm = norm_sqrd.sqrt().ln();
+        let factor = if sqr.magnitude() <= CLOSED_FORM_TOLERANCE {
+            if scalar <= T::zero() {
+                return None;
+            }
+            T::one() / scalar
+        } else if sqr < T::zero() {
+            let len = (-sqr).sqrt();
+            len.atan2(&scalar) / len
+        } else {
+            let len = sqr.sqrt();
+            if scalar <= len {
+                return None;
+            }
+            (len.clone() / scalar).atanh() / len
+        };
+        Some(rest * factor + ln_norm)
+    }
 
+    /// # Logarithm Series
+    /// 
+    /// Takes square roots until self is near 1, sums the series of 
+    /// log(1 + x), then doubles it back up.
+    fn log_series(&self) -> Option<Multivector<T>> {
+        let one = Component::new(T::one(), vec![]).to_mv();
+        let mut current = self.clone();
+        let mut roots = 0;
+        while (&current - &one).abs_sum() > 0.25 {
+            if roots >= SERIES_LIMIT {
+                return None;
+            }
+            current = current.sqrt_series()?;
+            roots += 1;
+        }
+        let x = &current - &one;
+        let mut result = Multivector::zero();
+        let mut power = one;
+        for k in 1..SERIES_LIMIT * 4 {
+            power = &power * &x;
+            let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+            let term = &power * T::from_f64(sign / k as f64);
+            result = result + &term;
+            if term.abs_sum() <= f64::EPSILON * result.abs_sum() {
+                break;
+            }
+        }
+        Some(result * T::from_f64(2.0_f64.powi(roots as i32)))
+    }
+
+    /// # Square Root
+    /// 
+    /// The square root of a rotor, IE the rotor which does half the rotation.
+    /// 
+    /// Returns None if there is no real square root, such as for -1.
+    /// 
+    /// ## Logic
+    /// 
+    /// A rotor R with R~R = ρ^2 has the root √ρ (1 + R/ρ)/|1 + R/ρ|, so long
+    /// as (1 + R/ρ) times it's reverse is a scalar. This covers rotors of 
+    /// simple bivectors in any plane, and translators.
+    /// 
+    /// Anything else uses the Denman-Beavers iteration.
+    pub fn sqrt(&self) -> Option<Multivector<T>> {
+        if let Some(norm_sqrd) = (self * self.reverse()).as_scalar() {
+            if norm_sqrd > T::zero() {
+                let norm = norm_sqrd.sqrt();
+                let half = self * (T::one() / norm.clone()) + T::one();
+                if let Some(half_sqrd) = (&half * half.reverse()).as_scalar() {
+                    if half_sqrd > T::from_f64(CLOSED_FORM_TOLERANCE) {
+                        return Some(half * (norm.sqrt() / half_sqrd.sqrt()));
+                    }
+                }
+            }
+        }
+        self.sqrt_series()
+    }
+
+    /// # Square Root Series
+    /// 
+    /// Denman-Beavers iteration, Y -> (Y + Z^-1)/2 and Z -> (Z + Y^-1)/2, 
+    /// starting with Y = self and Z = 1. Y converges on the square root.
+    fn sqrt_series(&self) -> Option<Multivector<T>> {
+        let mut y = self.clone();
+        let mut z = Component::new(T::one(), vec![]).to_mv();
+        for _ in 0..SERIES_LIMIT {
+            let next_y = (&y + z.inverse()?) * T::from_f64(0.5);
+            let next_z = (&z + y.inverse()?) * T::from_f64(0.5);
+            let done = (&next_y - &y).abs_sum() <= CLOSED_FORM_TOLERANCE * next_y.abs_sum();
+            y = next_y;
+            z = next_z;
+            if done {
+                return Some(y);
+            }
+        }
+        None
+    }
+
+    /// # As Scalar
+    /// 
+    /// Gets the multivector as a scalar, if every other part is within 
+    /// CLOSED_FORM_TOLERANCE of zero, relative to it's size.
+    fn as_scalar(&self) -> Option<T> {
+        let scale = self.abs_sum().max(1.0);
+        let mut result = T::zero();
+        for comp in self.components.iter() {
+            if comp.grade() == 0 {
+                result = comp.mag.clone();
+            } else if comp.mag.magnitude() > CLOSED_FORM_TOLERANCE * scale {
+                return None;
+            }
+        }
+        Some(result)
+    }
+
+    /// # Absolute Sum
+    /// 
+    /// The sum of the absolute magnitude of every component. A rough size of
+    /// the multivector that works in any signature.
+    fn abs_sum(&self) -> f64 {
+        self.components.iter().map(|x| x.mag.magnitude()).sum()
+    }
+}
+
+impl Multivector {
     /// # From String
     /// 
     /// Takes a correctly formated string and returns a multivector based on it.
     /// 
     /// All multivectors come in the form 
-    /// Component[(+/-)Component]*.
-    pub fn from_string(val: &String) -> Result<Multivector, String> {
-        // get the indices of + and -
-        let mut accumulator = ZERO;
-        let mut working = val.as_str();
-        let mut pluses: Vec<usize> = val.match_indices('+').map(|x| x.0).collect();
-        let mut minuses: Vec<usize> = val.match_indices('-').map(|x| x.0).collect();
-        // sanity check for singular, unsigned components
-        if pluses.len() == 0 && minuses.len() == 0 {
-            accumulator = accumulator + Component::from_string(val)?;
-            return Ok(accumulator);
+    /// Component[(+/-)Component]*, where any part may also be a sum in 
+    /// parentheses, IE 2(P(1) + P(2))P(3). Whitespace is ignored.
+    /// 
+    /// Errors point at the offending text.
+    pub fn from_string(val: &str) -> Result<Multivector, ParseError> {
+        Multivector::from_string_named(val, &HashMap::new())
+    }
+
+    /// # From String Named
+    /// 
+    /// As From String, but bases may also be given by any name in names.
+    pub fn from_string_named(val: &str, names: &HashMap<String, ONBasis>) -> Result<Multivector, ParseError> {
+        literal::parse_multivector(val, names)
+    }
+}
+
+impl<T: Scalar + FromStr> FromStr for Multivector<T> {
+    type Err = ParseError;
+
+    /// As From String, for any scalar which can be read from a string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        literal::parse_multivector(s, &HashMap::new())
+    }
+}
+
+
+/// # Canonical Order
+/// 
+/// The order components are kept in within a Multivector. Lowest grade first,
+/// then by bases, compared one by one in ONBasis order.
+pub fn canonical_order<T: Scalar>(lhs: &Component<T>, rhs: &Component<T>) -> Ordering {
+    lhs.grade().cmp(&rhs.grade())
+        .then_with(|| lhs.bases().cmp(rhs.bases()))
+}
+
+/// # Merge
+/// 
+/// Merges two lists of components in canonical order into one, adding rhs
+/// scaled by sign. Like components are combined and dropped if they cancel.
+fn merge<T: Scalar>(lhs: &[Component<T>], rhs: &[Component<T>], sign: T) -> Vec<Component<T>> {
+    let mut result = Vec::with_capacity(lhs.len() + rhs.len());
+    let (mut lidx, mut ridx) = (0, 0);
+    while lidx < lhs.len() && ridx < rhs.len() {
+        let (left, right) = (&lhs[lidx], &rhs[ridx]);
+        match canonical_order(left, right) {
+            Ordering::Less => {
+                result.push(left.clone());
+                lidx += 1;
+            },
+            Ordering::Equal => {
+                let mag = left.mag.clone() + sign.clone() * right.mag.clone();
+                if !mag.is_zero() {
+                    result.push(Component { mag, bases: left.bases.clone() });
+                }
+                lidx += 1;
+                ridx += 1;
+            },
+            Ordering::Greater => {
+                result.push(right * sign.clone());
+                ridx += 1;
+            },
+        }
+    }
+    // one side has run out, the rest of the other goes on the end.
+    result.extend(lhs[lidx..].iter().cloned());
+    result.extend(rhs[ridx..].iter().map(|x| x * sign.clone()));
+    result
+}
+
+/// # Closed Form Tolerance
+/// 
+/// How small a value may be, relative to the size of the multivector, before
+/// exp, log, and sqrt treat it as zero.
+const CLOSED_FORM_TOLERANCE: f64 = 1e-12;
+
+/// # Series Limit
+/// 
+/// The most steps a series or iteration is allowed before giving up.
+const SERIES_LIMIT: usize = 64;
+
+/// # Blade Tolerance
+/// 
+/// How small a value may be, relative to the largest value, before we 
+/// consider it zero when checking for blades.
+pub const BLADE_TOLERANCE: f64 = 1e-10;
+
+/// # Null Space
+/// 
+/// Finds a basis for the null space of a matrix with the given number of 
+/// columns by reducing it to row echelon form with partial pivoting. 
+/// 
+/// Values within BLADE_TOLERANCE of the largest are treated as zero, sizes
+/// being compared by Scalar::magnitude, unless the scalar is exact, see
+/// Scalar::is_negligible.
+pub(crate) fn null_space<T: Scalar>(mut matrix: Vec<Vec<T>>, cols: usize) -> Vec<Vec<T>> {
+    let scale = matrix.iter().flatten().fold(0.0_f64, |acc, x| acc.max(x.magnitude()));
+    let mut pivots = vec![];
+    let mut free = vec![];
+    for col in 0..cols {
+        let rank = pivots.len();
+        let pivot = (rank..matrix.len())
+            .max_by(|a, b| matrix[*a][col].magnitude().total_cmp(&matrix[*b][col].magnitude()));
+        let Some(pivot) = pivot.filter(|x| !matrix[*x][col].is_negligible(scale * BLADE_TOLERANCE)) else {
+            free.push(col);
+            continue;
+        };
+        matrix.swap(rank, pivot);
+        // normalize the pivot row, then clear the column from every other row.
+        let lead = matrix[rank][col].clone();
+        matrix[rank].iter_mut().for_each(|x| *x = x.clone() / lead.clone());
+        let pivot_row = matrix[rank].clone();
+        for (idx, row) in matrix.iter_mut().enumerate() {
+            if idx == rank || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (val, piv) in row.iter_mut().zip(pivot_row.iter()) {
+                *val = val.clone() - factor.clone() * piv.clone();
+            }
+        }
+        pivots.push(col);
+    }
+    // each free column gives one vector in the null space.
+    free.iter().map(|&f| {
+        let mut result = vec![T::zero(); cols];
+        result[f] = T::one();
+        for (row, &col) in pivots.iter().enumerate() {
+            result[col] = -matrix[row][f].clone();
         }
-        while pluses.len() > 0 || minuses.len() > 0 {
-            let idx = if pluses.last().unwrap_or(&0) >
-            minuses.last().unwrap_or(&0) {
-                // if plus before minus, pop that end and go negative
-                pluses.pop().unwrap()
-            } else {
-                minuses.pop().unwrap()
-            };
-
-            let curr = working.split_at(idx);
-            working = curr.0;
-            let curr = curr.1;
-            accumulator = accumulator + Component::from_string(&String::from(curr))?;
+        result
+    }).collect()
+}
+
+/// # Singular Tolerance
+/// 
+/// How small a pivot may be, relative to the largest value in the matrix, 
+/// before we consider the matrix singular.
+const SINGULAR_TOLERANCE: f64 = 1e-12;
+
+/// # Solve
+/// 
+/// Solves an augmented square matrix (last column is the right hand side) by
+/// Gaussian Elimination with partial pivoting.
+/// 
+/// Returns None if the matrix is singular, IE a pivot is negligible next to
+/// the largest value, see Scalar::is_negligible. Sizes are compared by 
+/// Scalar::magnitude.
+fn solve<T: Scalar>(mut matrix: Vec<Vec<T>>) -> Option<Vec<T>> {
+    let size = matrix.len();
+    let scale = matrix.iter().flatten().fold(0.0_f64, |acc, x| acc.max(x.magnitude()));
+    for col in 0..size {
+        // pick the largest remaining value in this column as our pivot.
+        let pivot = (col..size)
+            .max_by(|a, b| matrix[*a][col].magnitude().total_cmp(&matrix[*b][col].magnitude()))
+            .unwrap();
+        if matrix[pivot][col].is_negligible(scale * SINGULAR_TOLERANCE) {
+            return None;
         }
-        // get any remaining components
-        if working.len() > 0 {
-            accumulator = accumulator + Component::from_string(&String::from(working))?;
+        matrix.swap(col, pivot);
+        // clear the column out of every other row.
+        let pivot_row = matrix[col].clone();
+        for (idx, row) in matrix.iter_mut().enumerate() {
+            if idx == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone() / pivot_row[col].clone();
+            for (val, piv) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                *val = val.clone() - factor.clone() * piv.clone();
+            }
         }
-        Ok(accumulator)
     }
+    Some((0..size).map(|row| matrix[row][size].clone() / matrix[row][row].clone()).collect())
 }
 
+impl<T: Scalar> fmt::Display for Multivector<T> {
+    /// Writes a Multivector as a string, IE 1 + 3p1p2.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_named(&HashMap::new()))
+    }
+}
 
 // Equality
-impl PartialEq for Multivector {
+impl<T: Scalar> PartialEq for Multivector<T> {
     fn eq(&self, other: &Self) -> bool {
         // check that we have the same number of components
         if self.components.len() != other.components.len() {
@@ -449,143 +1128,146 @@ impl PartialEq for Multivector {
                 return false;
             }
         }
-        return true;
+        true
     }
 }
 
 // Addition
 // mv + mv
-impl ops::Add for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add for Multivector<T> {
+    type Output = Multivector<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
         self.multivector_add(&rhs)
     }
 }
 // mv + &mv
-impl ops::Add<&Multivector> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Multivector) -> Self::Output {
-        self.multivector_add(&rhs)
+    fn add(self, rhs: &Multivector<T>) -> Self::Output {
+        self.multivector_add(rhs)
     }
 }
 // &mv + mv
-impl ops::Add<Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: Multivector) -> Self::Output {
+    fn add(self, rhs: Multivector<T>) -> Self::Output {
         self.multivector_add(&rhs)
     }
 }
 // &mv + &mv
-impl ops::Add<&Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Multivector) -> Self::Output {
-        self.multivector_add(&rhs)
+    fn add(self, rhs: &Multivector<T>) -> Self::Output {
+        self.multivector_add(rhs)
     }
 }
 
 // mv + comp
-impl ops::Add<Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: Component) -> Self::Output {
+    fn add(self, rhs: Component<T>) -> Self::Output {
         self.component_add(&rhs)
     }
 }
 // &mv + comp
-impl ops::Add<Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: Component) -> Self::Output {
+    fn add(self, rhs: Component<T>) -> Self::Output {
         self.component_add(&rhs)
     }
 }
 // mv + &comp
-impl ops::Add<&Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Component) -> Self::Output {
+    fn add(self, rhs: &Component<T>) -> Self::Output {
         self.component_add(rhs)
     }
 }
 // &mv + &comp
-impl ops::Add<&Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Component) -> Self::Output {
+    fn add(self, rhs: &Component<T>) -> Self::Output {
         self.component_add(rhs)
     }
 }
 
 // comp + mv
-impl ops::Add<Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: Multivector) -> Self::Output {
+    fn add(self, rhs: Multivector<T>) -> Self::Output {
         rhs.component_add(&self)
     }
 }
 // &comp + mv
-impl ops::Add<Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: Multivector) -> Self::Output {
-        rhs.component_add(&self)
+    fn add(self, rhs: Multivector<T>) -> Self::Output {
+        rhs.component_add(self)
     }
 }
 // comp + &mv
-impl ops::Add<&Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Multivector) -> Self::Output {
+    fn add(self, rhs: &Multivector<T>) -> Self::Output {
         rhs.component_add(&self)
     }
 }
 // &comp + &mv
-impl ops::Add<&Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &Multivector) -> Self::Output {
-        rhs.component_add(&self)
+    fn add(self, rhs: &Multivector<T>) -> Self::Output {
+        rhs.component_add(self)
     }
 }
 
 // mv  + scalar
-impl ops::Add<f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: f64) -> Self::Output {
+    fn add(self, rhs: T) -> Self::Output {
         self.scalar_add(&rhs)
     }
 }
 // &mv + scalar
-impl ops::Add<f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: f64) -> Self::Output {
+    fn add(self, rhs: T) -> Self::Output {
         self.scalar_add(&rhs)
     }
 }
 // mv  + &scalar
-impl ops::Add<&f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &f64) -> Self::Output {
+    fn add(self, rhs: &T) -> Self::Output {
         self.scalar_add(rhs)
     }
 }
 // &mv + &scalar
-impl ops::Add<&f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Add<&T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn add(self, rhs: &f64) -> Self::Output {
+    fn add(self, rhs: &T) -> Self::Output {
         self.scalar_add(rhs)
     }
 }
 
+// A scalar on the left can't be generic, as we can't implement Add for
+// every T, so these, like the rest with a scalar on the left, are f64 only.
+
 // scalar  + mv 
 impl ops::Add<Multivector> for f64 {
     type Output = Multivector;
@@ -607,7 +1289,7 @@ impl ops::Add<Multivector> for &f64 {
     type Output = Multivector;
 
     fn add(self, rhs: Multivector) -> Self::Output {
-        rhs.scalar_add(&self)
+        rhs.scalar_add(self)
     }
 }
 // &scalar + &mv
@@ -615,140 +1297,140 @@ impl ops::Add<&Multivector> for &f64 {
     type Output = Multivector;
 
     fn add(self, rhs: &Multivector) -> Self::Output {
-        rhs.scalar_add(&self)
+        rhs.scalar_add(self)
     }
 }
 
 // subtraction
 // mv - mv
-impl ops::Sub for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub for Multivector<T> {
+    type Output = Multivector<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        self.multivector_add(&-rhs)
+        self.multivector_sub(&rhs)
     }
 }
 // mv - &mv
-impl ops::Sub<&Multivector> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Multivector) -> Self::Output {
-        self.multivector_add(&-rhs)
+    fn sub(self, rhs: &Multivector<T>) -> Self::Output {
+        self.multivector_sub(rhs)
     }
 }
 // &mv - mv
-impl ops::Sub<Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: Multivector) -> Self::Output {
-        self.multivector_add(&-rhs)
+    fn sub(self, rhs: Multivector<T>) -> Self::Output {
+        self.multivector_sub(&rhs)
     }
 }
 // &mv - &mv
-impl ops::Sub<&Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Multivector) -> Self::Output {
-        self.multivector_add(&-rhs)
+    fn sub(self, rhs: &Multivector<T>) -> Self::Output {
+        self.multivector_sub(rhs)
     }
 }
 
 // mv - comp
-impl ops::Sub<Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: Component) -> Self::Output {
+    fn sub(self, rhs: Component<T>) -> Self::Output {
         self.component_add(&-rhs)
     }
 }
 // &mv - comp
-impl ops::Sub<Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: Component) -> Self::Output {
+    fn sub(self, rhs: Component<T>) -> Self::Output {
         self.component_add(&-rhs)
     }
 }
 // mv - &comp
-impl ops::Sub<&Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Component) -> Self::Output {
+    fn sub(self, rhs: &Component<T>) -> Self::Output {
         self.component_add(&-rhs)
     }
 }
 // &mv - &comp
-impl ops::Sub<&Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Component) -> Self::Output {
+    fn sub(self, rhs: &Component<T>) -> Self::Output {
         self.component_add(&-rhs)
     }
 }
 
 // comp - mv
-impl ops::Sub<Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: Multivector) -> Self::Output {
+    fn sub(self, rhs: Multivector<T>) -> Self::Output {
         -rhs.component_add(&-self)
     }
 }
 // &comp - mv
-impl ops::Sub<Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: Multivector) -> Self::Output {
+    fn sub(self, rhs: Multivector<T>) -> Self::Output {
         -rhs.component_add(&-self)
     }
 }
 // comp - &mv
-impl ops::Sub<&Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Multivector) -> Self::Output {
+    fn sub(self, rhs: &Multivector<T>) -> Self::Output {
         -rhs.component_add(&-self)
     }
 }
 // &comp - &mv
-impl ops::Sub<&Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &Multivector) -> Self::Output {
+    fn sub(self, rhs: &Multivector<T>) -> Self::Output {
         -rhs.component_add(&-self)
     }
 }
 
 // mv - scalar
-impl ops::Sub<f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: f64) -> Self::Output {
+    fn sub(self, rhs: T) -> Self::Output {
         self.scalar_add(&-rhs)
     }
 }
 // &mv - scalar
-impl ops::Sub<f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: f64) -> Self::Output {
+    fn sub(self, rhs: T) -> Self::Output {
         self.scalar_add(&-rhs)
     }
 }
 // mv - &scalar
-impl ops::Sub<&f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &f64) -> Self::Output {
-        self.scalar_add(&-rhs)
+    fn sub(self, rhs: &T) -> Self::Output {
+        self.scalar_add(&-rhs.clone())
     }
 }
 // &mv - &scalar
-impl ops::Sub<&f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Sub<&T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn sub(self, rhs: &f64) -> Self::Output {
-        self.scalar_add(&-rhs)
+    fn sub(self, rhs: &T) -> Self::Output {
+        self.scalar_add(&-rhs.clone())
     }
 }
 
@@ -787,35 +1469,35 @@ impl ops::Sub<&Multivector> for &f64 {
 
 // scalar multiplication
 // f64  * mv
-impl ops::Mul<f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         self.scalar_mult(rhs)
     }
 }
 // &f64 * mv
-impl ops::Mul<&f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &f64) -> Self::Output {
-        self.scalar_mult(*rhs)
+    fn mul(self, rhs: &T) -> Self::Output {
+        self.scalar_mult(rhs.clone())
     }
 }
 // f64  * &mv
-impl ops::Mul<f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         self.scalar_mult(rhs)
     }
 }
 // &f64 * &mv
-impl ops::Mul<&f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &f64) -> Self::Output {
-        self.scalar_mult(*rhs)
+    fn mul(self, rhs: &T) -> Self::Output {
+        self.scalar_mult(rhs.clone())
     }
 }
 
@@ -854,101 +1536,101 @@ impl ops::Mul<&Multivector> for &f64 {
 
 // Component Mult
 // comp * mv
-impl ops::Mul<Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: Multivector) -> Self::Output {
+    fn mul(self, rhs: Multivector<T>) -> Self::Output {
         self.geo_product_mv(&rhs)
     }
 }
 // &comp * mv
-impl ops::Mul<Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: Multivector) -> Self::Output {
+    fn mul(self, rhs: Multivector<T>) -> Self::Output {
         self.geo_product_mv(&rhs)
     }
 }
 // comp * &mv
-impl ops::Mul<&Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &Multivector) -> Self::Output {
+    fn mul(self, rhs: &Multivector<T>) -> Self::Output {
         self.geo_product_mv(rhs)
     }
 }
 // &comp * &mv
-impl ops::Mul<&Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &Multivector) -> Self::Output {
+    fn mul(self, rhs: &Multivector<T>) -> Self::Output {
         self.geo_product_mv(rhs)
     }
 }
 
 // mv * comp
-impl ops::Mul<Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: Component) -> Self::Output {
+    fn mul(self, rhs: Component<T>) -> Self::Output {
         self.geo_prod_comp(&rhs)
     }
 }
 // &mv * comp
-impl ops::Mul<Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: Component) -> Self::Output {
+    fn mul(self, rhs: Component<T>) -> Self::Output {
         self.geo_prod_comp(&rhs)
     }
 }
 // mv * &comp
-impl ops::Mul<&Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &Component) -> Self::Output {
-        self.geo_prod_comp(&rhs)
+    fn mul(self, rhs: &Component<T>) -> Self::Output {
+        self.geo_prod_comp(rhs)
     }
 }
 // &mv * &comp
-impl ops::Mul<&Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &Component) -> Self::Output {
-        self.geo_prod_comp(&rhs)
+    fn mul(self, rhs: &Component<T>) -> Self::Output {
+        self.geo_prod_comp(rhs)
     }
 }
 
 // Geometric Product
 // mv * mv
-impl ops::Mul<Multivector> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: Multivector) -> Self::Output {
+    fn mul(self, rhs: Multivector<T>) -> Self::Output {
         self.geo_prod_mv(&rhs)
     }
 }
 // &mv * mv
-impl ops::Mul<&Multivector> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &Multivector) -> Self::Output {
+    fn mul(self, rhs: &Multivector<T>) -> Self::Output {
         self.geo_prod_mv(rhs)
     }
 }
 // mv * &mv
-impl ops::Mul<Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: Multivector) -> Self::Output {
+    fn mul(self, rhs: Multivector<T>) -> Self::Output {
         self.geo_prod_mv(&rhs)
     }
 }
 // &mv * &mv
-impl ops::Mul<&Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Mul<&Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn mul(self, rhs: &Multivector) -> Self::Output {
+    fn mul(self, rhs: &Multivector<T>) -> Self::Output {
         self.geo_prod_mv(rhs)
     }
 }
@@ -988,150 +1670,368 @@ impl ops::BitXor<&Multivector> for &f64 {
 }
 
 // mv ^ f64
-impl ops::BitXor<f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: f64) -> Self::Output {
+    fn bitxor(self, rhs: T) -> Self::Output {
         self.scalar_mult(rhs)
     }
 }
 // &mv ^ f64
-impl ops::BitXor<f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: f64) -> Self::Output {
+    fn bitxor(self, rhs: T) -> Self::Output {
         self.scalar_mult(rhs)
     }
 }
 // mv ^ &f64
-impl ops::BitXor<&f64> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&T> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &f64) -> Self::Output {
-        self.scalar_mult(*rhs)
+    fn bitxor(self, rhs: &T) -> Self::Output {
+        self.scalar_mult(rhs.clone())
     }
 }
 // &mv ^ &f64
-impl ops::BitXor<&f64> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&T> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &f64) -> Self::Output {
-        self.scalar_mult(*rhs)
+    fn bitxor(self, rhs: &T) -> Self::Output {
+        self.scalar_mult(rhs.clone())
     }
 }
 
 // component outer product
 // comp ^ mv
-impl ops::BitXor<Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: Multivector) -> Self::Output {
-        (-rhs).outer_prod_comp(&self)
+    fn bitxor(self, rhs: Multivector<T>) -> Self::Output {
+        self.to_mv().outer_product_mv(&rhs)
     }
 }
 // &comp ^ mv
-impl ops::BitXor<Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: Multivector) -> Self::Output {
-        (-rhs).outer_prod_comp(&self)
+    fn bitxor(self, rhs: Multivector<T>) -> Self::Output {
+        self.to_mv().outer_product_mv(&rhs)
     }
 }
 // comp ^ &mv
-impl ops::BitXor<&Multivector> for Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &Multivector) -> Self::Output {
-        (-rhs).outer_prod_comp(&self)
+    fn bitxor(self, rhs: &Multivector<T>) -> Self::Output {
+        self.to_mv().outer_product_mv(rhs)
     }
 }
 // &comp ^ &mv
-impl ops::BitXor<&Multivector> for &Component {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &Multivector) -> Self::Output {
-        (-rhs).outer_prod_comp(&self)
+    fn bitxor(self, rhs: &Multivector<T>) -> Self::Output {
+        self.to_mv().outer_product_mv(rhs)
     }
 }
 
 // mv ^ comp
-impl ops::BitXor<Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: Component) -> Self::Output {
+    fn bitxor(self, rhs: Component<T>) -> Self::Output {
         self.outer_prod_comp(&rhs)
     }
 }
 // &mv ^ comp
-impl ops::BitXor<Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: Component) -> Self::Output {
+    fn bitxor(self, rhs: Component<T>) -> Self::Output {
         self.outer_prod_comp(&rhs)
     }
 }
 // mv ^ &comp
-impl ops::BitXor<&Component> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &Component) -> Self::Output {
-        self.outer_prod_comp(&rhs)
+    fn bitxor(self, rhs: &Component<T>) -> Self::Output {
+        self.outer_prod_comp(rhs)
     }
 }
 // &mv ^ &comp
-impl ops::BitXor<&Component> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &Component) -> Self::Output {
-        self.outer_prod_comp(&rhs)
+    fn bitxor(self, rhs: &Component<T>) -> Self::Output {
+        self.outer_prod_comp(rhs)
     }
 }
 
 // multivector outer product
 // mv ^ mv
-impl ops::BitXor<Multivector> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: Multivector) -> Self::Output {
+    fn bitxor(self, rhs: Multivector<T>) -> Self::Output {
         self.outer_product_mv(&rhs)
     }
 }
 // &mv ^ mv
-impl ops::BitXor<&Multivector> for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &Multivector) -> Self::Output {
-        self.outer_product_mv(&rhs)
+    fn bitxor(self, rhs: &Multivector<T>) -> Self::Output {
+        self.outer_product_mv(rhs)
     }
 }
 // mv ^ &mv
-impl ops::BitXor<Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: Multivector) -> Self::Output {
+    fn bitxor(self, rhs: Multivector<T>) -> Self::Output {
         self.outer_product_mv(&rhs)
     }
 }
 // &mv ^ &mv
-impl ops::BitXor<&Multivector> for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::BitXor<&Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
 
-    fn bitxor(self, rhs: &Multivector) -> Self::Output {
-        self.outer_product_mv(&rhs)
+    fn bitxor(self, rhs: &Multivector<T>) -> Self::Output {
+        self.outer_product_mv(rhs)
+    }
+}
+
+// Left Contraction <<
+// mv << mv
+impl<T: Scalar> ops::Shl<Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: Multivector<T>) -> Self::Output {
+        self.left_cont_mv(&rhs)
+    }
+}
+// &mv << mv
+impl<T: Scalar> ops::Shl<Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: Multivector<T>) -> Self::Output {
+        self.left_cont_mv(&rhs)
+    }
+}
+// mv << &mv
+impl<T: Scalar> ops::Shl<&Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: &Multivector<T>) -> Self::Output {
+        self.left_cont_mv(rhs)
+    }
+}
+// &mv << &mv
+impl<T: Scalar> ops::Shl<&Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: &Multivector<T>) -> Self::Output {
+        self.left_cont_mv(rhs)
+    }
+}
+
+// mv << comp
+impl<T: Scalar> ops::Shl<Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: Component<T>) -> Self::Output {
+        self.left_cont_comp(&rhs)
+    }
+}
+// &mv << comp
+impl<T: Scalar> ops::Shl<Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: Component<T>) -> Self::Output {
+        self.left_cont_comp(&rhs)
+    }
+}
+// mv << &comp
+impl<T: Scalar> ops::Shl<&Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: &Component<T>) -> Self::Output {
+        self.left_cont_comp(rhs)
+    }
+}
+// &mv << &comp
+impl<T: Scalar> ops::Shl<&Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: &Component<T>) -> Self::Output {
+        self.left_cont_comp(rhs)
+    }
+}
+
+// comp << mv
+impl<T: Scalar> ops::Shl<Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: Multivector<T>) -> Self::Output {
+        self.left_cont_mv(&rhs)
+    }
+}
+// &comp << mv
+impl<T: Scalar> ops::Shl<Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: Multivector<T>) -> Self::Output {
+        self.left_cont_mv(&rhs)
+    }
+}
+// comp << &mv
+impl<T: Scalar> ops::Shl<&Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: &Multivector<T>) -> Self::Output {
+        self.left_cont_mv(rhs)
+    }
+}
+// &comp << &mv
+impl<T: Scalar> ops::Shl<&Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
+
+    fn shl(self, rhs: &Multivector<T>) -> Self::Output {
+        self.left_cont_mv(rhs)
+    }
+}
+
+// Right Contraction >>
+// mv >> mv
+impl<T: Scalar> ops::Shr<Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: Multivector<T>) -> Self::Output {
+        self.right_cont_mv(&rhs)
+    }
+}
+// &mv >> mv
+impl<T: Scalar> ops::Shr<Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: Multivector<T>) -> Self::Output {
+        self.right_cont_mv(&rhs)
+    }
+}
+// mv >> &mv
+impl<T: Scalar> ops::Shr<&Multivector<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: &Multivector<T>) -> Self::Output {
+        self.right_cont_mv(rhs)
+    }
+}
+// &mv >> &mv
+impl<T: Scalar> ops::Shr<&Multivector<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: &Multivector<T>) -> Self::Output {
+        self.right_cont_mv(rhs)
+    }
+}
+
+// mv >> comp
+impl<T: Scalar> ops::Shr<Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: Component<T>) -> Self::Output {
+        self.right_cont_comp(&rhs)
+    }
+}
+// &mv >> comp
+impl<T: Scalar> ops::Shr<Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: Component<T>) -> Self::Output {
+        self.right_cont_comp(&rhs)
+    }
+}
+// mv >> &comp
+impl<T: Scalar> ops::Shr<&Component<T>> for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: &Component<T>) -> Self::Output {
+        self.right_cont_comp(rhs)
+    }
+}
+// &mv >> &comp
+impl<T: Scalar> ops::Shr<&Component<T>> for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: &Component<T>) -> Self::Output {
+        self.right_cont_comp(rhs)
+    }
+}
+
+// comp >> mv
+impl<T: Scalar> ops::Shr<Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: Multivector<T>) -> Self::Output {
+        self.right_cont_mv(&rhs)
+    }
+}
+// &comp >> mv
+impl<T: Scalar> ops::Shr<Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: Multivector<T>) -> Self::Output {
+        self.right_cont_mv(&rhs)
+    }
+}
+// comp >> &mv
+impl<T: Scalar> ops::Shr<&Multivector<T>> for Component<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: &Multivector<T>) -> Self::Output {
+        self.right_cont_mv(rhs)
+    }
+}
+// &comp >> &mv
+impl<T: Scalar> ops::Shr<&Multivector<T>> for &Component<T> {
+    type Output = Multivector<T>;
+
+    fn shr(self, rhs: &Multivector<T>) -> Self::Output {
+        self.right_cont_mv(rhs)
+    }
+}
+
+// Reversion
+// !mv
+impl<T: Scalar> ops::Not for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn not(self) -> Self::Output {
+        self.reverse()
+    }
+}
+// !&mv
+impl<T: Scalar> ops::Not for &Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn not(self) -> Self::Output {
+        self.reverse()
     }
 }
 
 // Negative
-impl ops::Neg for Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Neg for Multivector<T> {
+    type Output = Multivector<T>;
 
     fn neg(self) -> Self::Output {
         self.negative()
     }
 }
 
-impl ops::Neg for &Multivector {
-    type Output = Multivector;
+impl<T: Scalar> ops::Neg for &Multivector<T> {
+    type Output = Multivector<T>;
 
     fn neg(self) -> Self::Output {
         self.negative()
@@ -0,0 +1,260 @@
+use crate::{basis::ONBasis, blade::Geometry, component::Component, multivector::Multivector, versor::Versor};
+
+/// # Projective Geometric Algebra
+///
+/// Plane based PGA, Cl(n,0,1). Euclidean space of n dimensions, with the
+/// bases e1 to en as P(1) to P(n) and the degenerate e0 as Z(0).
+///
+/// Vectors are hyperplanes, n.e + d e0 being the plane n.x + d = 0, and
+/// points are the meet of n hyperplanes, so in 3D, planes are vectors, lines
+/// are bivectors, and points are trivectors. In 2D, lines are vectors and
+/// points are bivectors.
+///
+/// Meet is the outer product, Join is the regressive product, and rigid
+/// motions are Versors, made of translators and rotors.
+///
+/// Meant for 2D and 3D, though any dimension works.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pga {
+    /// How many euclidean dimensions we have.
+    dimension: usize,
+    /// The geometry, with bases named e0 to en.
+    geometry: Geometry,
+}
+
+impl Pga {
+    /// # New
+    ///
+    /// Creates PGA for euclidean space of n dimensions.
+    ///
+    /// Returns an error if n is 0.
+    pub fn new(dimension: usize) -> Result<Pga, String> {
+        if dimension == 0 {
+            return Err(String::from("PGA needs at least 1 dimension."));
+        }
+        let mut named = vec![(String::from("e0"), ONBasis::Z(0))];
+        named.extend((1..=dimension).map(|i| (format!("e{}", i), ONBasis::P(i))));
+        Ok(Pga { dimension, geometry: Geometry::from_named(named)? })
+    }
+
+    /// # Dimension
+    ///
+    /// The number of euclidean dimensions.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// # Geometry
+    ///
+    /// The geometry Cl(n,0,1), with bases named e0 to en.
+    pub fn geometry(&self) -> &Geometry {
+        &self.geometry
+    }
+
+    /// # e0
+    ///
+    /// The degenerate basis, the plane at infinity.
+    pub fn e0(&self) -> Multivector {
+        ONBasis::Z(0).to_mv()
+    }
+
+    /// # Check Coordinates
+    ///
+    /// Ensures we have one coordinate per dimension.
+    fn check_coords(&self, coords: &[f64]) -> Result<(), String> {
+        if coords.len() == self.dimension {
+            Ok(())
+        } else {
+            Err(format!("Expected {} coordinates, found {}.", self.dimension, coords.len()))
+        }
+    }
+
+    /// # Point
+    ///
+    /// The point at the given coordinates, as the meet of the hyperplanes
+    /// x_i = coords_i. Has a weight of 1.
+    pub fn point(&self, coords: &[f64]) -> Result<Multivector, String> {
+        self.check_coords(coords)?;
+        let mut result = Component::new(1.0, vec![]).to_mv();
+        for (idx, coord) in coords.iter().enumerate() {
+            let plane = Multivector::new(vec![
+                Component::new(1.0, vec![ONBasis::P(idx + 1)]),
+                Component::new(-coord, vec![ONBasis::Z(0)]),
+            ]);
+            result = result ^ plane;
+        }
+        Ok(result)
+    }
+
+    /// # Direction
+    ///
+    /// The ideal point (point at infinity) in the given direction. Has a
+    /// weight of 0.
+    pub fn direction(&self, coords: &[f64]) -> Result<Multivector, String> {
+        Ok(self.point(coords)? - self.point(&vec![0.0; self.dimension])?)
+    }
+
+    /// # Plane
+    ///
+    /// The hyperplane normal.x + offset = 0. A plane in 3D and a line in 2D.
+    pub fn plane(&self, normal: &[f64], offset: f64) -> Result<Multivector, String> {
+        self.check_coords(normal)?;
+        let mut result = vec![Component::new(offset, vec![ONBasis::Z(0)])];
+        for (idx, val) in normal.iter().enumerate() {
+            result.push(Component::new(*val, vec![ONBasis::P(idx + 1)]));
+        }
+        Ok(Multivector::new(result))
+    }
+
+    /// # Line
+    ///
+    /// The line through two points, from `from` towards `to`.
+    pub fn line(&self, from: &[f64], to: &[f64]) -> Result<Multivector, String> {
+        Ok(self.join(&self.point(from)?, &self.point(to)?))
+    }
+
+    /// # Coordinates
+    ///
+    /// The euclidean coordinates of a point, undoing point.
+    ///
+    /// Returns None if the point is ideal, IE has no weight.
+    pub fn coords(&self, point: &Multivector) -> Option<Vec<f64>> {
+        let origin = self.point(&vec![0.0; self.dimension]).ok()?;
+        let weight = self.coefficient(point, &origin.components()[0])?;
+        let mut result = vec![];
+        for idx in 0..self.dimension {
+            let mut unit = vec![0.0; self.dimension];
+            unit[idx] = 1.0;
+            let axis = self.direction(&unit).ok()?;
+            let coord = self.coefficient(point, &axis.components()[0]).unwrap_or(0.0);
+            result.push(coord / axis.components()[0].mag / weight);
+        }
+        Some(result)
+    }
+
+    /// # Coefficient
+    ///
+    /// The magnitude of the component of mv with the same bases as comp.
+    fn coefficient(&self, mv: &Multivector, comp: &Component) -> Option<f64> {
+        mv.components().iter()
+            .find(|x| x.same_bases(comp))
+            .map(|x| x.mag)
+            .filter(|x| *x != 0.0)
+    }
+
+    /// # Complement
+    ///
+    /// The right complement of mv, taking each basis blade A to the blade B
+    /// with A ^ B = I. Unlike the dual, it works with degenerate bases.
+    pub fn complement(&self, mv: &Multivector) -> Multivector {
+        self.geometry.complement(mv)
+    }
+
+    /// # Inverse Complement
+    ///
+    /// The left complement, which undoes complement. Takes each basis blade
+    /// A to the blade B with B ^ A = I.
+    pub fn inverse_complement(&self, mv: &Multivector) -> Multivector {
+        self.geometry.inverse_complement(mv)
+    }
+
+    /// # Meet
+    ///
+    /// The intersection of two elements, their outer product.
+    pub fn meet(&self, lhs: &Multivector, rhs: &Multivector) -> Multivector {
+        lhs ^ rhs
+    }
+
+    /// # Join
+    ///
+    /// The element spanning two elements, their regressive product. IE the
+    /// line through two points, or the plane through a line and a point.
+    pub fn join(&self, lhs: &Multivector, rhs: &Multivector) -> Multivector {
+        lhs.regressive(rhs, &self.geometry)
+    }
+
+    /// # Bulk Norm
+    ///
+    /// The size of the parts of mv that include e0. For a normalized point,
+    /// this is it's distance from the origin.
+    pub fn bulk_norm(&self, mv: &Multivector) -> f64 {
+        mv.components().iter()
+            .filter(|x| x.bases().contains(&ONBasis::Z(0)))
+            .map(|x| x.mag * x.mag)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// # Weight Norm
+    ///
+    /// The size of the parts of mv without e0, the usual norm sqrt(|X X~|).
+    /// Zero for ideal elements, which lie at infinity.
+    pub fn weight_norm(&self, mv: &Multivector) -> f64 {
+        mv.components().iter()
+            .filter(|x| !x.bases().contains(&ONBasis::Z(0)))
+            .map(|x| x.mag * x.mag)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// # Normalize
+    ///
+    /// Scales mv to a weight norm of 1.
+    ///
+    /// Returns None if mv is ideal.
+    pub fn normalize(&self, mv: &Multivector) -> Option<Multivector> {
+        let weight = self.weight_norm(mv);
+        if weight == 0.0 {
+            return None;
+        }
+        Some(mv * (1.0 / weight))
+    }
+
+    /// # Distance
+    ///
+    /// The euclidean distance between two points, the weight norm of the
+    /// line joining them once both are normalized.
+    ///
+    /// Returns None if either point is ideal.
+    pub fn distance(&self, lhs: &Multivector, rhs: &Multivector) -> Option<f64> {
+        let line = self.join(&self.normalize(lhs)?, &self.normalize(rhs)?);
+        Some(self.weight_norm(&line))
+    }
+
+    /// # Translator
+    ///
+    /// The motor which moves everything by offset, 1 - (e0 ^ t)/2, where t is
+    /// the vector of the offset.
+    pub fn translator(&self, offset: &[f64]) -> Result<Versor, String> {
+        self.check_coords(offset)?;
+        let mut result = vec![Component::new(1.0, vec![])];
+        for (idx, val) in offset.iter().enumerate() {
+            result.push(Component::new(-val / 2.0, vec![ONBasis::Z(0), ONBasis::P(idx + 1)]));
+        }
+        Versor::new(&Multivector::new(result))
+    }
+
+    /// # Rotor
+    ///
+    /// The motor which rotates by angle around an axis, cos(θ/2) - sin(θ/2) L,
+    /// where L is the normalized axis. The axis is a line in 3D and a point
+    /// in 2D.
+    /// 
+    /// Positive angles turn counter-clockwise, looking back down the axis in
+    /// 3D, IE following the right hand rule.
+    ///
+    /// Returns an error if the axis is ideal.
+    pub fn rotor(&self, axis: &Multivector, angle: f64) -> Result<Versor, String> {
+        let Some(axis) = self.normalize(axis) else {
+            return Err(format!("'{}' is ideal, so cannot be rotated around.", axis));
+        };
+        Versor::new(&(axis * -(angle / 2.0).sin() + (angle / 2.0).cos()))
+    }
+
+    /// # Motor
+    ///
+    /// A rigid motion, rotating first, then translating.
+    pub fn motor(&self, rotor: &Versor, translator: &Versor) -> Versor {
+        translator * rotor
+    }
+}
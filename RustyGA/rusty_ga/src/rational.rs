@@ -0,0 +1,384 @@
+use std::{cmp::Ordering, fmt, ops, str::FromStr};
+
+use crate::{bigint::BigInt, scalar::Scalar};
+
+/// # Rational
+///
+/// An exact fraction of two BigInts, for when floating point noise would get
+/// in the way, like checking identities with ==.
+///
+/// Always kept in lowest terms with a positive denominator, so equal values
+/// are always equal.
+///
+/// Plugs into the algebra as a Scalar, so Multivector<Rational> is an exact
+/// Multivector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    /// The numerator, carrying the sign.
+    num: BigInt,
+    /// The denominator, always positive.
+    den: BigInt,
+}
+
+impl Rational {
+    /// # New
+    ///
+    /// Creates num/den in lowest terms.
+    ///
+    /// Returns None if den is 0.
+    pub fn new(num: BigInt, den: BigInt) -> Option<Rational> {
+        if den.is_zero() {
+            return None;
+        }
+        let gcd = num.gcd(&den);
+        let (num, den) = if den.is_negative() {
+            (-(&num / &gcd), -(&den / &gcd))
+        } else {
+            (&num / &gcd, &den / &gcd)
+        };
+        Some(Rational { num, den })
+    }
+
+    /// # From Integers
+    ///
+    /// Creates num/den from plain integers.
+    ///
+    /// Returns None if den is 0.
+    pub fn from_ints(num: i64, den: i64) -> Option<Rational> {
+        Rational::new(BigInt::from(num), BigInt::from(den))
+    }
+
+    /// # Numerator
+    pub fn numerator(&self) -> &BigInt {
+        &self.num
+    }
+
+    /// # Denominator
+    ///
+    /// Always positive.
+    pub fn denominator(&self) -> &BigInt {
+        &self.den
+    }
+
+    /// # Is Integer
+    pub fn is_integer(&self) -> bool {
+        self.den == BigInt::one()
+    }
+
+    /// # Reciprocal
+    ///
+    /// 1/self.
+    ///
+    /// Returns None if self is 0.
+    pub fn recip(&self) -> Option<Rational> {
+        Rational::new(self.den.clone(), self.num.clone())
+    }
+
+    /// # To f64
+    ///
+    /// The nearest f64.
+    ///
+    /// Rather than dividing the two halves, which overflows for large
+    /// values, we shift the numerator so the quotient has 64 bits and
+    /// scale back afterwards. Any remainder can only break a tie, so it is 
+    /// kept as an extra 1 bit below the quotient for BigInt::to_f64 to round.
+    pub fn to_f64(&self) -> f64 {
+        if self.num.is_zero() {
+            return 0.0;
+        }
+        let shift = 64 + self.den.bits() as i64 - self.num.bits() as i64;
+        let (quot, rem) = if shift >= 0 {
+            self.num.shl(shift as usize).div_rem(&self.den)
+        } else {
+            self.num.div_rem(&self.den.shl((-shift) as usize))
+        }.expect("Denominator is never 0.");
+        let (quot, shift) = if rem.is_zero() {
+            (quot, shift)
+        } else {
+            let sticky = if quot.is_negative() { -BigInt::one() } else { BigInt::one() };
+            (quot.shl(1) + sticky, shift + 1)
+        };
+        let shift = shift.clamp(-(i32::MAX as i64), i32::MAX as i64) as i32;
+        // split the scaling so neither half overflows on it's own.
+        quot.to_f64() * 2f64.powi(-shift / 2) * 2f64.powi(-shift + shift / 2)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(val: i64) -> Self {
+        Rational { num: BigInt::from(val), den: BigInt::one() }
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(val: BigInt) -> Self {
+        Rational { num: val, den: BigInt::one() }
+    }
+}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::from(0i64)
+    }
+
+    fn one() -> Self {
+        Rational::from(1i64)
+    }
+
+    /// Exact, as every finite f64 is a fraction over a power of 2. Infinities
+    /// and NaN have no rational value, so become 0.
+    fn from_f64(val: f64) -> Self {
+        if !val.is_finite() {
+            return Rational::zero();
+        }
+        let bits = val.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i64;
+        let frac = bits & ((1 << 52) - 1);
+        // subnormals have no implicit leading bit.
+        let (mant, exp) = if exp == 0 { (frac, -1074) } else { (frac | (1 << 52), exp - 1075) };
+        let mant = if val < 0.0 { -BigInt::from(mant) } else { BigInt::from(mant) };
+        if exp >= 0 {
+            Rational::from(mant.shl(exp as usize))
+        } else {
+            Rational::new(mant, BigInt::one().shl((-exp) as usize)).expect("Power of 2 is never 0.")
+        }
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.to_f64().abs()
+    }
+
+    /// Exact, so only 0 is negligible, however small the limit.
+    fn is_negligible(&self, _limit: f64) -> bool {
+        self.is_zero()
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, so cross multiplying keeps the order.
+        (&self.num * &other.den).cmp(&(&other.num * &self.den))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    /// Writes num/den, or just num for integers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = String;
+
+    /// Reads an integer, a decimal like -1.25 or .5, or a fraction of
+    /// them like 1/3 or 1.5/2. Decimals are read exactly, so 0.1 is 1/10.
+    ///
+    /// Any of them may have an exponent, like 1e-3 or 2.5E6, which is also
+    /// exact.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((num, den)) = s.split_once('/') {
+            let num: Rational = num.parse()?;
+            let den: Rational = den.parse()?;
+            return den.recip()
+                .map(|den| num * den)
+                .ok_or(format!("'{}' divides by zero.", s));
+        }
+        if let Some((mant, exp)) = s.split_once(['e', 'E']) {
+            let mant: Rational = mant.parse()?;
+            let exp: i32 = exp.parse().map_err(|_| format!("'{}' is not a number.", s))?;
+            let scale = Rational::from(BigInt::from(10i64).pow(exp.unsigned_abs()));
+            return Ok(if exp < 0 { mant / scale } else { mant * scale });
+        }
+        let (sign, rest) = match s.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (whole, decimal) = rest.split_once('.').unwrap_or((rest, ""));
+        if (whole.is_empty() && decimal.is_empty()) ||
+        !whole.bytes().chain(decimal.bytes()).all(|x| x.is_ascii_digit()) {
+            return Err(format!("'{}' is not a number.", s));
+        }
+        let num: BigInt = format!("{}{}{}", sign, whole, decimal).parse()?;
+        let den = BigInt::from(10i64).pow(decimal.len() as u32);
+        Ok(Rational::new(num, den).expect("Power of 10 is never 0."))
+    }
+}
+
+// Arithmetic
+
+// &rat + &rat
+impl ops::Add<&Rational> for &Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &Rational) -> Self::Output {
+        Rational::new(&self.num * &rhs.den + &rhs.num * &self.den, &self.den * &rhs.den)
+            .expect("Denominators are never 0.")
+    }
+}
+
+// rat + &rat
+impl ops::Add<&Rational> for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &Rational) -> Self::Output {
+        &self + rhs
+    }
+}
+
+// &rat + rat
+impl ops::Add<Rational> for &Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Self::Output {
+        self + &rhs
+    }
+}
+
+// rat + rat
+impl ops::Add<Rational> for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+// &rat - &rat
+impl ops::Sub<&Rational> for &Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &Rational) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+// rat - &rat
+impl ops::Sub<&Rational> for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &Rational) -> Self::Output {
+        &self - rhs
+    }
+}
+
+// &rat - rat
+impl ops::Sub<Rational> for &Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Self::Output {
+        self - &rhs
+    }
+}
+
+// rat - rat
+impl ops::Sub<Rational> for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+// &rat * &rat
+impl ops::Mul<&Rational> for &Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &Rational) -> Self::Output {
+        Rational::new(&self.num * &rhs.num, &self.den * &rhs.den)
+            .expect("Denominators are never 0.")
+    }
+}
+
+// rat * &rat
+impl ops::Mul<&Rational> for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &Rational) -> Self::Output {
+        &self * rhs
+    }
+}
+
+// &rat * rat
+impl ops::Mul<Rational> for &Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Self::Output {
+        self * &rhs
+    }
+}
+
+// rat * rat
+impl ops::Mul<Rational> for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// &rat / &rat
+impl ops::Div<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Panics if rhs is 0, like integer division. Use recip to check first.
+    fn div(self, rhs: &Rational) -> Self::Output {
+        Rational::new(&self.num * &rhs.den, &self.den * &rhs.num)
+            .expect("Cannot divide by zero.")
+    }
+}
+
+// rat / &rat
+impl ops::Div<&Rational> for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: &Rational) -> Self::Output {
+        &self / rhs
+    }
+}
+
+// &rat / rat
+impl ops::Div<Rational> for &Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Self::Output {
+        self / &rhs
+    }
+}
+
+// rat / rat
+impl ops::Div<Rational> for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+// -&rat
+impl ops::Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational { num: -&self.num, den: self.den.clone() }
+    }
+}
+
+// -rat
+impl ops::Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational { num: -self.num, den: self.den }
+    }
+}
@@ -0,0 +1,208 @@
+use std::{fmt::{Debug, Display}, ops::{Add, Div, Mul, Neg, Sub}};
+
+/// # Scalar
+///
+/// The type of the magnitude of a Component, and so of everything built from
+/// Components. f64 is the default everywhere, so Component is Component<f64>.
+///
+/// Anything which acts like a field will do, such as f32, exact rationals,
+/// complex numbers, or dual numbers. The algebra itself (sums, products,
+/// reversion and so on) only needs the arithmetic here.
+///
+/// Parts which need more than arithmetic, like exp, log, and sqrt, are only
+/// available for Scalars which are also Real.
+pub trait Scalar: Clone + Debug + Display + PartialEq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+    + Neg<Output = Self> {
+    /// # Zero
+    ///
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// # One
+    ///
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// # From f64
+    ///
+    /// Converts an f64 into the scalar, as closely as it can.
+    fn from_f64(val: f64) -> Self;
+
+    /// # Magnitude
+    ///
+    /// A rough size of the scalar as an f64, never negative. Used to choose
+    /// pivots and compare against tolerances in numerical routines, like
+    /// inverse and blade breakdown.
+    fn magnitude(&self) -> f64;
+
+    /// # Is Zero
+    ///
+    /// Whether the scalar is exactly zero. Zero magnitudes are dropped from
+    /// Multivectors.
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// # Is Negligible
+    ///
+    /// Whether the scalar is small enough to treat as zero in numerical 
+    /// routines, like picking a pivot, where limit is the largest magnitude 
+    /// which counts as rounding noise.
+    ///
+    /// By default, anything with a magnitude of at most limit. Exact 
+    /// scalars have no rounding noise, so should only count exact zeros.
+    fn is_negligible(&self, limit: f64) -> bool {
+        self.magnitude() <= limit
+    }
+}
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_f64(val: f64) -> Self {
+        val
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Scalar for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_f64(val: f64) -> Self {
+        val as f32
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+/// # Real
+///
+/// A Scalar which acts like the real numbers, with an order and the usual
+/// functions. This is what exp, log, and sqrt of a Multivector need.
+///
+/// Comparisons only need to be good enough to pick a branch, IE is this
+/// negative, so dual numbers compare by their value alone.
+pub trait Real: Scalar + PartialOrd {
+    /// # Square Root
+    fn sqrt(&self) -> Self;
+
+    /// # Exponential
+    fn exp(&self) -> Self;
+
+    /// # Natural Logarithm
+    fn ln(&self) -> Self;
+
+    /// # Sine
+    fn sin(&self) -> Self;
+
+    /// # Cosine
+    fn cos(&self) -> Self;
+
+    /// # Hyperbolic Sine
+    fn sinh(&self) -> Self;
+
+    /// # Hyperbolic Cosine
+    fn cosh(&self) -> Self;
+
+    /// # Inverse Hyperbolic Tangent
+    fn atanh(&self) -> Self;
+
+    /// # Four Quadrant Arctangent
+    ///
+    /// The angle of the point (x, self), as f64::atan2.
+    fn atan2(&self, x: &Self) -> Self;
+}
+
+impl Real for f64 {
+    fn sqrt(&self) -> Self {
+        f64::sqrt(*self)
+    }
+
+    fn exp(&self) -> Self {
+        f64::exp(*self)
+    }
+
+    fn ln(&self) -> Self {
+        f64::ln(*self)
+    }
+
+    fn sin(&self) -> Self {
+        f64::sin(*self)
+    }
+
+    fn cos(&self) -> Self {
+        f64::cos(*self)
+    }
+
+    fn sinh(&self) -> Self {
+        f64::sinh(*self)
+    }
+
+    fn cosh(&self) -> Self {
+        f64::cosh(*self)
+    }
+
+    fn atanh(&self) -> Self {
+        f64::atanh(*self)
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        f64::atan2(*self, *x)
+    }
+}
+
+impl Real for f32 {
+    fn sqrt(&self) -> Self {
+        f32::sqrt(*self)
+    }
+
+    fn exp(&self) -> Self {
+        f32::exp(*self)
+    }
+
+    fn ln(&self) -> Self {
+        f32::ln(*self)
+    }
+
+    fn sin(&self) -> Self {
+        f32::sin(*self)
+    }
+
+    fn cos(&self) -> Self {
+        f32::cos(*self)
+    }
+
+    fn sinh(&self) -> Self {
+        f32::sinh(*self)
+    }
+
+    fn cosh(&self) -> Self {
+        f32::cosh(*self)
+    }
+
+    fn atanh(&self) -> Self {
+        f32::atanh(*self)
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        f32::atan2(*self, *x)
+    }
+}
@@ -0,0 +1,570 @@
+use std::{cmp::Ordering, collections::{BTreeMap, BTreeSet}, fmt, ops};
+
+use crate::{rational::Rational, scalar::Scalar};
+
+/// # Symbolic
+///
+/// A symbolic coefficient, a fraction of two polynomials in named variables
+/// with exact Rational coefficients. IE a·c, -3/2·a^2·b + 1, or 1/(a + b).
+///
+/// Plugs into the algebra as a Scalar, so products of Multivector<Symbolic>
+/// give closed form formulas. IE (a P(1) + b P(2)) * c P(1) is
+/// a·c - b·c P(1)P(2), with the bases in their usual canonical order.
+///
+/// ## Simplification
+///
+/// Polynomials are always fully expanded with like terms collected. For
+/// fractions we cancel common monomials and constants, and cancel entirely
+/// when the numerator or denominator divides the other. Other common factors
+/// may be left in place, but equality compares by cross multiplying, so
+/// equal values always compare equal.
+#[derive(Debug, Clone)]
+pub struct Symbolic {
+    /// The numerator.
+    num: Polynomial,
+    /// The denominator, never zero, with a leading coefficient of 1.
+    den: Polynomial,
+}
+
+impl Symbolic {
+    /// # Variable
+    ///
+    /// The variable with the given name.
+    pub fn var(name: &str) -> Symbolic {
+        Symbolic { num: Polynomial::var(name), den: Polynomial::constant(Rational::one()) }
+    }
+
+    /// # Constant
+    pub fn constant(val: Rational) -> Symbolic {
+        Symbolic { num: Polynomial::constant(val), den: Polynomial::constant(Rational::one()) }
+    }
+
+    /// # New
+    ///
+    /// Simplifies num/den. den must not be zero.
+    fn new(num: Polynomial, den: Polynomial) -> Symbolic {
+        if num.is_zero() {
+            return Symbolic::zero();
+        }
+        // cancel any shared monomial, IE a^2·b/a = a·b
+        let common = num.monomial_gcd().gcd(&den.monomial_gcd());
+        let (mut num, mut den) = (num.div_monomial(&common), den.div_monomial(&common));
+        // cancel completely if one divides the other.
+        if let Some(quot) = num.div_exact(&den) {
+            num = quot;
+            den = Polynomial::constant(Rational::one());
+        } else if let Some(quot) = den.div_exact(&num) {
+            num = Polynomial::constant(Rational::one());
+            den = quot;
+        }
+        // make the denominator monic, so constants live in the numerator.
+        let lead = den.leading().expect("Denominator is never zero.").1
+            .recip().expect("Coefficients are never zero.");
+        Symbolic { num: num.scale(&lead), den: den.scale(&lead) }
+    }
+
+    /// # As Constant
+    ///
+    /// The value, if it has no variables.
+    pub fn as_constant(&self) -> Option<Rational> {
+        Some(self.num.as_constant()? / self.den.as_constant()?)
+    }
+
+    /// # Variables
+    ///
+    /// The names of every variable used, in alphabetical order.
+    pub fn variables(&self) -> Vec<String> {
+        self.num.terms.keys().chain(self.den.terms.keys())
+            .flat_map(|x| x.0.iter().map(|(name, _)| name.clone()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// # Pow
+    ///
+    /// Raises self to an integer power, negative powers giving the
+    /// reciprocal.
+    ///
+    /// Returns None if self is zero and the power is negative.
+    pub fn pow(&self, exp: i32) -> Option<Symbolic> {
+        let (num, den) = if exp < 0 {
+            if self.num.is_zero() {
+                return None;
+            }
+            (self.den.pow(exp.unsigned_abs()), self.num.pow(exp.unsigned_abs()))
+        } else {
+            (self.num.pow(exp as u32), self.den.pow(exp as u32))
+        };
+        Some(Symbolic::new(num, den))
+    }
+}
+
+impl From<i64> for Symbolic {
+    fn from(val: i64) -> Self {
+        Symbolic::constant(Rational::from(val))
+    }
+}
+
+impl From<Rational> for Symbolic {
+    fn from(val: Rational) -> Self {
+        Symbolic::constant(val)
+    }
+}
+
+impl Scalar for Symbolic {
+    fn zero() -> Self {
+        Symbolic::constant(Rational::zero())
+    }
+
+    fn one() -> Self {
+        Symbolic::constant(Rational::one())
+    }
+
+    fn from_f64(val: f64) -> Self {
+        Symbolic::constant(Rational::from_f64(val))
+    }
+
+    /// Constants have their usual size. Anything with variables isn't a
+    /// number, so is treated as size 1, IE generically non zero.
+    fn magnitude(&self) -> f64 {
+        match self.as_constant() {
+            Some(val) => val.magnitude(),
+            None => 1.0,
+        }
+    }
+
+    /// Exact, so only 0 is negligible, however small the limit.
+    fn is_negligible(&self, _limit: f64) -> bool {
+        self.is_zero()
+    }
+}
+
+impl PartialEq for Symbolic {
+    fn eq(&self, other: &Self) -> bool {
+        self.num.mul(&other.den) == other.num.mul(&self.den)
+    }
+}
+
+impl fmt::Display for Symbolic {
+    /// Writes num/den, or just num when there's no denominator. Sums are
+    /// wrapped in parentheses, so they read correctly in front of bases.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wrap = |poly: &Polynomial| if poly.terms.len() > 1 {
+            format!("({})", poly)
+        } else {
+            poly.to_string()
+        };
+        if self.den.as_constant() == Some(Rational::one()) {
+            write!(f, "{}", wrap(&self.num))
+        } else {
+            write!(f, "{}/{}", wrap(&self.num), wrap(&self.den))
+        }
+    }
+}
+
+// Arithmetic
+
+// &sym + &sym
+impl ops::Add<&Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn add(self, rhs: &Symbolic) -> Self::Output {
+        if self.den == rhs.den {
+            return Symbolic::new(self.num.add(&rhs.num), self.den.clone());
+        }
+        Symbolic::new(self.num.mul(&rhs.den).add(&rhs.num.mul(&self.den)), self.den.mul(&rhs.den))
+    }
+}
+
+// sym + &sym
+impl ops::Add<&Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn add(self, rhs: &Symbolic) -> Self::Output {
+        &self + rhs
+    }
+}
+
+// &sym + sym
+impl ops::Add<Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn add(self, rhs: Symbolic) -> Self::Output {
+        self + &rhs
+    }
+}
+
+// sym + sym
+impl ops::Add<Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn add(self, rhs: Symbolic) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+// &sym - &sym
+impl ops::Sub<&Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn sub(self, rhs: &Symbolic) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+// sym - &sym
+impl ops::Sub<&Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn sub(self, rhs: &Symbolic) -> Self::Output {
+        &self - rhs
+    }
+}
+
+// &sym - sym
+impl ops::Sub<Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn sub(self, rhs: Symbolic) -> Self::Output {
+        self - &rhs
+    }
+}
+
+// sym - sym
+impl ops::Sub<Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn sub(self, rhs: Symbolic) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+// &sym * &sym
+impl ops::Mul<&Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn mul(self, rhs: &Symbolic) -> Self::Output {
+        Symbolic::new(self.num.mul(&rhs.num), self.den.mul(&rhs.den))
+    }
+}
+
+// sym * &sym
+impl ops::Mul<&Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn mul(self, rhs: &Symbolic) -> Self::Output {
+        &self * rhs
+    }
+}
+
+// &sym * sym
+impl ops::Mul<Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn mul(self, rhs: Symbolic) -> Self::Output {
+        self * &rhs
+    }
+}
+
+// sym * sym
+impl ops::Mul<Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn mul(self, rhs: Symbolic) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// &sym / &sym
+impl ops::Div<&Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    /// Panics if rhs is 0, like integer division.
+    fn div(self, rhs: &Symbolic) -> Self::Output {
+        if rhs.num.is_zero() {
+            panic!("Cannot divide by zero.");
+        }
+        Symbolic::new(self.num.mul(&rhs.den), self.den.mul(&rhs.num))
+    }
+}
+
+// sym / &sym
+impl ops::Div<&Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn div(self, rhs: &Symbolic) -> Self::Output {
+        &self / rhs
+    }
+}
+
+// &sym / sym
+impl ops::Div<Symbolic> for &Symbolic {
+    type Output = Symbolic;
+
+    fn div(self, rhs: Symbolic) -> Self::Output {
+        self / &rhs
+    }
+}
+
+// sym / sym
+impl ops::Div<Symbolic> for Symbolic {
+    type Output = Symbolic;
+
+    fn div(self, rhs: Symbolic) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+// -&sym
+impl ops::Neg for &Symbolic {
+    type Output = Symbolic;
+
+    fn neg(self) -> Self::Output {
+        Symbolic { num: self.num.scale(&-Rational::one()), den: self.den.clone() }
+    }
+}
+
+// -sym
+impl ops::Neg for Symbolic {
+    type Output = Symbolic;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// # Monomial
+///
+/// A product of variables raised to powers, IE a^2·c. Kept sorted by name,
+/// without any zero powers, so 1 is empty.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+struct Monomial(Vec<(String, u32)>);
+
+impl Monomial {
+    /// # Degree
+    ///
+    /// The total of all powers.
+    fn degree(&self) -> u32 {
+        self.0.iter().map(|(_, pow)| pow).sum()
+    }
+
+    /// # Power Of
+    ///
+    /// The power of the given variable, 0 if it's not used.
+    fn power_of(&self, name: &str) -> u32 {
+        self.0.iter().find(|(x, _)| x == name).map_or(0, |(_, pow)| *pow)
+    }
+
+    /// # Names
+    ///
+    /// Every variable used in either monomial, in order.
+    fn names<'a>(&'a self, rhs: &'a Monomial) -> BTreeSet<&'a str> {
+        self.0.iter().chain(rhs.0.iter()).map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// # Combine
+    ///
+    /// Builds a monomial by combining the powers of each variable.
+    fn combine(&self, rhs: &Monomial, op: impl Fn(u32, u32) -> u32) -> Monomial {
+        Monomial(self.names(rhs).into_iter()
+            .map(|name| (name.to_string(), op(self.power_of(name), rhs.power_of(name))))
+            .filter(|(_, pow)| *pow > 0)
+            .collect())
+    }
+
+    /// # Multiply
+    fn mul(&self, rhs: &Monomial) -> Monomial {
+        self.combine(rhs, |l, r| l + r)
+    }
+
+    /// # Divide
+    ///
+    /// Returns None if rhs does not divide self.
+    fn div(&self, rhs: &Monomial) -> Option<Monomial> {
+        if rhs.0.iter().any(|(name, pow)| self.power_of(name) < *pow) {
+            return None;
+        }
+        Some(self.combine(rhs, |l, r| l - r))
+    }
+
+    /// # Greatest Common Divisor
+    fn gcd(&self, rhs: &Monomial) -> Monomial {
+        self.combine(rhs, |l, r| l.min(r))
+    }
+}
+
+impl Ord for Monomial {
+    /// Graded lexicographic order, IE higher degree first, then by the
+    /// powers of each variable in alphabetical order. Respects
+    /// multiplication, which polynomial division needs.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.degree().cmp(&other.degree()).then_with(|| {
+            self.names(other).into_iter()
+                .map(|name| self.power_of(name).cmp(&other.power_of(name)))
+                .find(|x| x.is_ne())
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+impl PartialOrd for Monomial {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Monomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = self.0.iter()
+            .map(|(name, pow)| if *pow == 1 { name.clone() } else { format!("{}^{}", name, pow) })
+            .collect::<Vec<_>>();
+        write!(f, "{}", parts.join("·"))
+    }
+}
+
+/// # Polynomial
+///
+/// A sum of monomials with Rational coefficients, kept in monomial order
+/// with no zero coefficients, so equal polynomials are always equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+struct Polynomial {
+    terms: BTreeMap<Monomial, Rational>,
+}
+
+impl Polynomial {
+    /// # Constant
+    fn constant(val: Rational) -> Polynomial {
+        Polynomial::from_terms(vec![(Monomial::default(), val)])
+    }
+
+    /// # Variable
+    fn var(name: &str) -> Polynomial {
+        Polynomial::from_terms(vec![(Monomial(vec![(name.to_string(), 1)]), Rational::one())])
+    }
+
+    /// # From Terms
+    ///
+    /// Sums the terms given, dropping any which cancel out.
+    fn from_terms(terms: impl IntoIterator<Item = (Monomial, Rational)>) -> Polynomial {
+        let mut result: BTreeMap<Monomial, Rational> = BTreeMap::new();
+        for (mono, coef) in terms {
+            let sum = match result.remove(&mono) {
+                Some(prev) => prev + coef,
+                None => coef,
+            };
+            if !sum.is_zero() {
+                result.insert(mono, sum);
+            }
+        }
+        Polynomial { terms: result }
+    }
+
+    /// # Is Zero
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// # As Constant
+    ///
+    /// The value, if it has no variables.
+    fn as_constant(&self) -> Option<Rational> {
+        match self.terms.iter().collect::<Vec<_>>()[..] {
+            [] => Some(Rational::zero()),
+            [(mono, coef)] if mono.0.is_empty() => Some(coef.clone()),
+            _ => None,
+        }
+    }
+
+    /// # Leading
+    ///
+    /// The highest term in monomial order.
+    fn leading(&self) -> Option<(&Monomial, &Rational)> {
+        self.terms.last_key_value()
+    }
+
+    /// # Add
+    fn add(&self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::from_terms(self.terms.clone().into_iter().chain(rhs.terms.clone()))
+    }
+
+    /// # Multiply
+    fn mul(&self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::from_terms(self.terms.iter()
+            .flat_map(|(lm, lc)| rhs.terms.iter().map(move |(rm, rc)| (lm.mul(rm), lc * rc))))
+    }
+
+    /// # Scale
+    fn scale(&self, val: &Rational) -> Polynomial {
+        Polynomial::from_terms(self.terms.iter().map(|(mono, coef)| (mono.clone(), coef * val)))
+    }
+
+    /// # Pow
+    fn pow(&self, exp: u32) -> Polynomial {
+        (0..exp).fold(Polynomial::constant(Rational::one()), |acc, _| acc.mul(self))
+    }
+
+    /// # Monomial GCD
+    ///
+    /// The largest monomial dividing every term.
+    fn monomial_gcd(&self) -> Monomial {
+        let mut terms = self.terms.keys();
+        let first = terms.next().cloned().unwrap_or_default();
+        terms.fold(first, |acc, x| acc.gcd(x))
+    }
+
+    /// # Divide by Monomial
+    ///
+    /// The monomial must divide every term.
+    fn div_monomial(&self, mono: &Monomial) -> Polynomial {
+        Polynomial::from_terms(self.terms.iter()
+            .map(|(x, coef)| (x.div(mono).expect("Monomial divides every term."), coef.clone())))
+    }
+
+    /// # Divide Exactly
+    ///
+    /// Polynomial long division, returning the quotient only if there is no
+    /// remainder. rhs must not be zero.
+    ///
+    /// If rhs divides self, the leading term of rhs divides the leading
+    /// term of what's left at every step, so we can give up as soon as it
+    /// doesn't.
+    fn div_exact(&self, rhs: &Polynomial) -> Option<Polynomial> {
+        let (lead_mono, lead_coef) = rhs.leading()?;
+        let mut rem = self.clone();
+        let mut quot = vec![];
+        while let Some((mono, coef)) = rem.leading() {
+            let term = Polynomial::from_terms(vec![(mono.div(lead_mono)?, coef / lead_coef)]);
+            rem = rem.add(&term.mul(rhs).scale(&-Rational::one()));
+            quot.extend(term.terms);
+        }
+        Some(Polynomial::from_terms(quot))
+    }
+}
+
+impl fmt::Display for Polynomial {
+    /// Writes the terms from highest to lowest, IE a^2 - 2·a·b + 3.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut result = String::new();
+        for (idx, (mono, coef)) in self.terms.iter().rev().enumerate() {
+            let negative = *coef < Rational::zero();
+            if idx == 0 {
+                result.push_str(if negative { "-" } else { "" });
+            } else {
+                result.push_str(if negative { " - " } else { " + " });
+            }
+            let coef = if negative { -coef } else { coef.clone() };
+            if mono.0.is_empty() {
+                result.push_str(&coef.to_string());
+            } else if coef == Rational::one() {
+                result.push_str(&mono.to_string());
+            } else {
+                result.push_str(&format!("{}·{}", coef, mono));
+            }
+        }
+        write!(f, "{}", result)
+    }
+}
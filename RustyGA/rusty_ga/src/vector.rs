@@ -1,6 +1,5 @@
-use std::ops::Mul;
 
-use crate::{basis::{self, ONBasis}, blade::{self, Blade}, component::Component, multivector::Multivector};
+use crate::{basis::ONBasis, blade::Blade, component::Component, multivector::Multivector, scalar::Scalar};
 
 /// # D1 Vector
 /// 
@@ -17,15 +16,24 @@ use crate::{basis::{self, ONBasis}, blade::{self, Blade}, component::Component,
 /// 
 /// TODO: Consider making component into a special restricted variant for Vector. Would reduce size, increase speed, and reduce extraction code for the basis.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Vector {
+pub struct Vector<T: Scalar = f64> {
     /// The components that make up our Vector
-    pub components: Vec<Component>,
+    pub components: Vec<Component<T>>,
 }
 
 impl Vector {
     pub const ZERO: Vector = Vector { components: vec![] };
+}
 
-    pub fn components(&self) -> &[Component] {
+impl<T: Scalar> Vector<T> {
+    /// # Zero
+    /// 
+    /// The vector with no components, for any Scalar.
+    pub fn zero() -> Vector<T> {
+        Vector { components: vec![] }
+    }
+
+    pub fn components(&self) -> &[Component<T>] {
         &self.components
     }
 
@@ -36,6 +44,13 @@ impl Vector {
         self.components.len()
     }
 
+    /// # Is Empty
+    /// 
+    /// Checks if the Vector has no components.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
     /// # New
     /// 
     /// Creates a new Vector.
@@ -44,8 +59,8 @@ impl Vector {
     /// 
     /// If a component is not of grade 1, it skips that component, so do be aware of
     /// that.
-    pub fn new(components: &Vec<Component>) -> Self {
-        let mut result = Vector::ZERO;
+    pub fn new(components: &Vec<Component<T>>) -> Self {
+        let mut result = Vector::zero();
         for component in components {
             result = result.comp_add(component);
         }
@@ -57,68 +72,40 @@ impl Vector {
     /// Adds a given component to a Vector and returns the result.
     /// 
     /// If component is not of grade 1, it returns the original Vector safely.
-    pub fn comp_add(&self, component: &Component) -> Self {
+    pub fn comp_add(&self, component: &Component<T>) -> Self {
         let mut result = self.clone();
         if component.grade() != 1 {
             return result;
         }
         // binary search for a match.
-        let mut low = 0;
-        let mut high = self.len().saturating_sub(1);
         let other_basis = component.bases[0];
-        while low <= high {
-            let mid = low + (high - low) / 2;
-            let current_basis = self.components[mid].bases[0];
-            if current_basis == other_basis { // found basis match, add and break out.
-                result.components.get_mut(mid).unwrap().mag += component.mag;
-                return result;
-            } else if current_basis < other_basis  { // too low
-                low = mid + 1;
-            } else { // too high
-                high = mid.saturating_sub(1);
+        match self.components.binary_search_by(|x| x.bases[0].cmp(&other_basis)) {
+            Ok(idx) => { // found basis match, add.
+                let sum = result.components[idx].mag.clone() + component.mag.clone();
+                result.components[idx].mag = sum;
+                if result.components[idx].mag.is_zero() { // drop it if it cancelled out.
+                    result.components.remove(idx);
+                }
+            },
+            Err(idx) => { // no match, insert it where it belongs.
+                result.components.insert(idx, component.clone());
             }
         }
-        // fi we get here, just insert the component properly and return that.
-        result.sorted_insert(component);
         result
     }
 
-    /// # Sorted Insert
-    /// 
-    /// Used to insert a component into a Vector in a way consistent with our 
-    /// pre-defined ordering. 
-    /// 
-    /// A shortcut for internal use only. All other 'insertions' should use comp_add to ensure proper use.
-    fn sorted_insert(&mut self, component: &Component) {
-        debug_assert_eq!(component.grade(), 1, "Component must be of grade 1! -> Component {:?}", component);
-
-        let mut idx = 0;
-        let comp_b = component.bases.first().unwrap();
-        while idx < self.len() {
-            let self_b = self.components.get(idx).unwrap().bases.first().unwrap();
-            match comp_b.cmp(&self_b) {
-                std::cmp::Ordering::Less => idx += 1,
-                std::cmp::Ordering::Equal => panic!("Should never reach here as components being added should already be guaranteed not duplicated."),
-                std::cmp::Ordering::Greater => {
-                    self.components.insert(idx, component.clone());
-                    break;
-                },
-            }
-        }
-    }
-
     /// # Vector Addition
     /// 
     /// Takes in two vectors and adds them together.
     /// 
     /// Does so intelligently by zipping them together.
     pub fn vec_add(&self, other: &Self) -> Self {
-        let mut result = Vector::ZERO;
+        let mut result = Vector::zero();
         let mut s_idx = 0;
         let mut o_idx = 0;
         while s_idx < self.len() && o_idx < other.len() {
             let self_comp = self.components.get(s_idx).unwrap();
-            let other_comp = self.components.get(o_idx).unwrap();
+            let other_comp = other.components.get(o_idx).unwrap();
             match self_comp.bases[0].cmp(&other_comp.bases[0]) {
                 std::cmp::Ordering::Less => {
                     // if self less than other, push the self_comp and increment self idx
@@ -129,8 +116,11 @@ impl Vector {
                     // if they are equal, increment both and add together.
                     s_idx += 1;
                     o_idx += 1;
-                    result.components.push(self_comp.force_comp_add(other_comp)
-                        .expect("Basis mismatch in components?"));
+                    let sum = self_comp.force_comp_add(other_comp)
+                        .expect("Basis mismatch in components?");
+                    if !sum.mag.is_zero() {
+                        result.components.push(sum);
+                    }
                 },
                 std::cmp::Ordering::Greater => {
                     // if self greater than other, push other and increment other_idx
@@ -144,7 +134,7 @@ impl Vector {
             result.components.push(self.components[s_idx].clone());
             s_idx += 1;
         }
-        while o_idx < self.len() {
+        while o_idx < other.len() {
             result.components.push(other.components[o_idx].clone());
             o_idx += 1;
         }
@@ -155,17 +145,17 @@ impl Vector {
     /// # Reverse
     /// 
     /// Reverses the vector, which is just the vector.
-    pub fn reverse(&self) -> Vector {
+    pub fn reverse(&self) -> Vector<T> {
         self.clone()
     }
 
     /// # Scalar Multiplication
     /// 
     /// Multiplies a vector by a scalar value. Shortcut for such events.
-    pub fn scalar_mult(&self, scalar: f64) -> Vector {
-        let mut result = Vector::ZERO;
+    pub fn scalar_mult(&self, scalar: T) -> Vector<T> {
+        let mut result = Vector::zero();
         for comp in self.components.iter() {
-            result.components.push(comp.scalar_mult(scalar));
+            result.components.push(comp.scalar_mult(scalar.clone()));
         }
         result
     }
@@ -175,22 +165,22 @@ impl Vector {
     /// Does a geometric product with a singular component.
     /// 
     /// Returns a multivector due to the possibility of grade splits.
-    pub fn component_geo_product(&self, component: &Component) -> Multivector {
-        let result = Multivector::ZERO;
+    pub fn component_geo_product(&self, component: &Component<T>) -> Multivector<T> {
+        let result = Multivector::zero();
         for comp in self.components.iter() {
             result.component_add(&comp.geo_product(component));
         }
 
-        return result;
+        result
     }
 
     /// # Scalar Product
     /// 
-    /// Dot product between two vectors. Always returns an f64, so will just do that.
+    /// Dot product between two vectors. Always returns a scalar, so will just do that.
     /// 
     /// This can only work between two vectors, as such, it only accepts a vector.
-    pub fn scalar_product(&self, rhs: &Self) -> f64 {
-        let mut result = 0.0;
+    pub fn scalar_product(&self, rhs: &Self) -> T {
+        let mut result = T::zero();
         // be smart and ZIP!
         let (mut lidx, mut ridx) = (0,0);
         while lidx < self.len() && ridx < rhs.len() {
@@ -205,7 +195,7 @@ impl Vector {
                     // if they are equal, increment both and add together.
                     lidx += 1;
                     ridx += 1;
-                    result += right.mag * left.mag;
+                    result = result + right.mag.clone() * left.mag.clone();
                 },
                 std::cmp::Ordering::Greater => {
                     // if self greater than other, push other and increment other_idx
@@ -223,21 +213,19 @@ impl Vector {
     /// Gets the outer product of two vectors, returning it in the form of a blade.
     /// 
     /// Blade can be 0.
-    pub fn outer_product_blade(&self, rhs: &Self) -> Blade {
-        let result = self.to_blade();
-        //result.outer_product_vec(rhs);
-        todo!()
+    pub fn outer_product_blade(&self, rhs: &Self) -> Blade<T> {
+        Blade::new(&[self.clone(), rhs.clone()])
     }
 
     /// # Outer Product
     /// 
     /// Outer Product with anotehr vector. Returns a multivector.
-    pub fn vector_outer_product_mv(&self, rhs: &Self) -> Multivector {
-        let result = Multivector::ZERO;
+    pub fn vector_outer_product_mv(&self, rhs: &Self) -> Multivector<T> {
+        let result = Multivector::zero();
         for lcomp in self.components.iter() {
             for rcomp in rhs.components.iter() {
                 let res = lcomp.outer_product(rcomp);
-                if res.mag != 0.0 {
+                if !res.mag.is_zero() {
                     result.component_add(&res);
                 }
             }
@@ -248,7 +236,7 @@ impl Vector {
     /// # Norm Squared
     /// 
     /// It gets the norm of the vector squared.
-    pub fn norm_sqrd(&self) -> f64 {
+    pub fn norm_sqrd(&self) -> T {
         // Norm_sqrd is effectively the dot product of a vector with itself.
         todo!()
     }
@@ -257,13 +245,13 @@ impl Vector {
     /// 
     /// Converts an ONBasis to a vector of unit length 1.
     pub fn from_orthonormal_basis(basis: ONBasis) -> Self {
-        Vector { components: vec![Component::new(1.0, vec![basis])] }
+        Vector { components: vec![Component::new(T::one(), vec![basis])] }
     }
 
     /// # From Component
     /// 
     /// Converts a component to a vector if the vector is of grade 1.
-    pub fn from_component(component: &Component) -> Option<Self> {
+    pub fn from_component(component: &Component<T>) -> Option<Self> {
         if component.grade() == 1 {
             Some(Vector { components: vec![component.clone()]})
         } else {
@@ -274,14 +262,14 @@ impl Vector {
     /// # To Blade
     /// 
     /// Converts a vector to blade.
-    pub fn to_blade(&self) -> Blade {
-        Blade::new(&vec![self.clone()])
+    pub fn to_blade(&self) -> Blade<T> {
+        Blade::new(std::slice::from_ref(self))
     }
 
     /// # To MV
     /// 
     /// Converts Vector to Multivector.
-    pub fn to_mv(&self) -> Multivector {
+    pub fn to_mv(&self) -> Multivector<T> {
         Multivector { components: self.components.clone(), blades: vec![self.to_blade()]}
     }
 }
\ No newline at end of file
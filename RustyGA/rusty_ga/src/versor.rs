@@ -0,0 +1,212 @@
+use std::ops;
+
+use crate::{basis::ONBasis, component::Component, multivector::Multivector, vector::Vector};
+
+/// # Versor Tolerance
+///
+/// How large a stray part may be, relative to the size of the result, before
+/// a multivector is rejected as a versor.
+pub const VERSOR_TOLERANCE: f64 = 1e-10;
+
+/// # Versor
+///
+/// A Versor is the geometric product of invertible vectors. Versors with an
+/// even number of vectors are rotors, odd ones include a reflection.
+///
+/// Versors act on other multivectors through the sandwich product, see apply.
+///
+/// The inverse is found once, on creation, so applying the versor many
+/// times is cheap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Versor {
+    /// The versor itself.
+    versor: Multivector,
+    /// The inverse of the versor.
+    inverse: Multivector,
+    /// Whether the versor is made of an odd number of vectors.
+    odd: bool,
+}
+
+impl Versor {
+    /// # New
+    ///
+    /// Creates a versor from a multivector.
+    ///
+    /// Returns an error if the multivector is not a versor. IE, it mixes
+    /// even and odd grades, has no inverse, or it's sandwich does not take
+    /// vectors to vectors.
+    pub fn new(versor: &Multivector) -> Result<Versor, String> {
+        let grades = versor.grades();
+        let Some(first) = grades.first() else {
+            return Err(String::from("Zero is not a versor."));
+        };
+        if grades.iter().any(|x| x % 2 != first % 2) {
+            return Err(format!("'{}' mixes even and odd grades, so is not a versor.", versor));
+        }
+        let Some(inverse) = versor.inverse() else {
+            return Err(format!("'{}' has no inverse, so is not a versor.", versor));
+        };
+        let result = Versor { versor: versor.clone(), inverse, odd: first % 2 == 1 };
+        // a versor must take every vector to a vector.
+        let mut bases: Vec<ONBasis> = vec![];
+        for comp in versor.components() {
+            for basis in comp.bases() {
+                if !bases.contains(basis) {
+                    bases.push(*basis);
+                }
+            }
+        }
+        for basis in bases {
+            let image = result.apply(&basis.to_mv());
+            let size: f64 = image.components().iter().map(|x| x.mag.abs()).sum();
+            if image.components().iter()
+                .any(|x| x.grade() != 1 && x.mag.abs() > VERSOR_TOLERANCE * size.max(1.0)) {
+                return Err(format!("'{}' does not take vectors to vectors, so is not a versor.", versor));
+            }
+        }
+        Ok(result)
+    }
+
+    /// # From Vectors
+    ///
+    /// Creates a versor from the geometric product of vectors, in order.
+    ///
+    /// Returns an error if any vector has no inverse. No vectors gives the
+    /// identity.
+    pub fn from_vectors(vectors: &[Vector]) -> Result<Versor, String> {
+        let mut versor = Multivector::new(vec![Component::new(1.0, vec![])]);
+        for vec in vectors {
+            let vec = Multivector::new(vec.components.clone());
+            if vec.inverse().is_none() {
+                return Err(format!("'{}' has no inverse, so cannot be part of a versor.", vec));
+            }
+            versor = versor * vec;
+        }
+        let Some(inverse) = versor.inverse() else {
+            return Err(format!("'{}' has no inverse, so is not a versor.", versor));
+        };
+        Ok(Versor { versor, inverse, odd: vectors.len() % 2 == 1 })
+    }
+
+    /// # From Bivector
+    ///
+    /// Creates the rotor exp(B) of a bivector B.
+    ///
+    /// Returns an error if B is not a bivector.
+    pub fn from_bivector(bivector: &Multivector) -> Result<Versor, String> {
+        if bivector.grades().iter().any(|x| *x != 2) {
+            return Err(format!("'{}' is not a bivector.", bivector));
+        }
+        Versor::new(&bivector.exp())
+    }
+
+    /// # To Multivector
+    ///
+    /// The versor as a multivector.
+    pub fn to_mv(&self) -> &Multivector {
+        &self.versor
+    }
+
+    /// # Is Odd
+    ///
+    /// Whether the versor is the product of an odd number of vectors.
+    pub fn is_odd(&self) -> bool {
+        self.odd
+    }
+
+    /// # Is Rotor
+    ///
+    /// Whether the versor is the product of an even number of vectors.
+    pub fn is_rotor(&self) -> bool {
+        !self.odd
+    }
+
+    /// # Apply
+    ///
+    /// Applies the versor to a multivector through the sandwich product,
+    /// V X V^-1. For odd versors, X is grade involuted first, so reflections
+    /// come out with the right sign.
+    pub fn apply(&self, rhs: &Multivector) -> Multivector {
+        if self.odd {
+            &self.versor * rhs.grade_involution() * &self.inverse
+        } else {
+            &self.versor * rhs * &self.inverse
+        }
+    }
+
+    /// # Compose
+    ///
+    /// Combines two versors into one. Applying the result is the same as
+    /// applying rhs, then self.
+    pub fn compose(&self, rhs: &Versor) -> Versor {
+        Versor {
+            versor: &self.versor * &rhs.versor,
+            inverse: &rhs.inverse * &self.inverse,
+            odd: self.odd != rhs.odd,
+        }
+    }
+
+    /// # Inverse
+    ///
+    /// The versor which undoes this one.
+    pub fn inverse(&self) -> Versor {
+        Versor { versor: self.inverse.clone(), inverse: self.versor.clone(), odd: self.odd }
+    }
+
+    /// # Normalize
+    ///
+    /// Scales the versor so V V~ = ±1. This does not change how it acts.
+    ///
+    /// Returns an error if V V~ is not a non-zero scalar, such as for some
+    /// versors made of null vectors.
+    pub fn normalize(&self) -> Result<Versor, String> {
+        let square = &self.versor * self.versor.reverse();
+        let size: f64 = square.components().iter().map(|x| x.mag.abs()).sum();
+        let norm = square.components().first()
+            .filter(|x| x.grade() == 0)
+            .map(|x| x.mag.abs().sqrt())
+            .filter(|_| square.components().iter().skip(1).all(|x| x.mag.abs() <= VERSOR_TOLERANCE * size));
+        let Some(norm) = norm else {
+            return Err(format!("'{}' has no magnitude to normalize by.", self.versor));
+        };
+        Ok(Versor {
+            versor: &self.versor * (1.0 / norm),
+            inverse: &self.inverse * norm,
+            odd: self.odd,
+        })
+    }
+}
+
+// Composition
+// versor * versor
+impl ops::Mul for Versor {
+    type Output = Versor;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+// versor * &versor
+impl ops::Mul<&Versor> for Versor {
+    type Output = Versor;
+
+    fn mul(self, rhs: &Versor) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+// &versor * versor
+impl ops::Mul<Versor> for &Versor {
+    type Output = Versor;
+
+    fn mul(self, rhs: Versor) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+// &versor * &versor
+impl ops::Mul<&Versor> for &Versor {
+    type Output = Versor;
+
+    fn mul(self, rhs: &Versor) -> Self::Output {
+        self.compose(rhs)
+    }
+}